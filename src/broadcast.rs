@@ -0,0 +1,280 @@
+//! Implements a channel that delivers each good to every subscribed [`Consumer`].
+use {
+    crate::{clone_name::CloneName, inventory::Inventory, sync::lock},
+    alloc::{
+        collections::VecDeque,
+        format,
+        string::String,
+        sync::{Arc, Weak},
+        vec::Vec,
+    },
+    core::{
+        cmp,
+        fmt::{self, Display, Formatter},
+        marker::PhantomData,
+    },
+    fehler::{throw, throws},
+    market::{
+        channel::{WithdrawnDemand, WithdrawnSupply},
+        Agent, Consumer, ConsumptionFlaws, EmptyStock, Failure, Fault, Producer, Recall,
+    },
+    std::sync::Mutex,
+};
+
+/// The defect thrown by a [`BroadcastReceiver`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BroadcastDefect {
+    /// The subscriber fell behind and the given number of goods were evicted before they were consumed.
+    Lagged(usize),
+    /// The channel is empty and all of its producers have been dropped.
+    Withdrawn,
+}
+
+impl Display for BroadcastDefect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Lagged(missed) => write!(f, "lagged behind by {} goods", missed),
+            Self::Withdrawn => write!(f, "{}", WithdrawnSupply::default()),
+        }
+    }
+}
+
+impl std::error::Error for BroadcastDefect {}
+
+impl From<WithdrawnSupply> for BroadcastDefect {
+    fn from(_: WithdrawnSupply) -> Self {
+        Self::Withdrawn
+    }
+}
+
+/// The goods stored for a single subscriber.
+#[derive(Debug)]
+struct Subscription<G> {
+    /// The goods that have not been consumed.
+    goods: VecDeque<G>,
+    /// The number of goods that were evicted since the last consumption.
+    missed: usize,
+}
+
+/// The state shared by all agents of a broadcast channel.
+#[derive(Debug)]
+struct Hub<G> {
+    /// The name of the channel.
+    name: String,
+    /// The capacity of each subscription.
+    size: usize,
+    /// The number of subscriptions that have been created.
+    subscription_count: usize,
+    /// The subscriptions, which are removed once their receiver is dropped.
+    subscriptions: Vec<Weak<Mutex<Subscription<G>>>>,
+}
+
+impl<G> Hub<G> {
+    /// Creates a [`BroadcastReceiver`] that is supplied by `supply`.
+    fn subscribe(hub: &Arc<Mutex<Self>>, supply: Weak<()>) -> BroadcastReceiver<G> {
+        let mut locked_hub = lock(hub);
+        let subscription = Arc::new(Mutex::new(Subscription {
+            goods: VecDeque::with_capacity(locked_hub.size),
+            missed: 0,
+        }));
+
+        locked_hub.subscription_count = locked_hub.subscription_count.saturating_add(1);
+        locked_hub.subscriptions.push(Arc::downgrade(&subscription));
+
+        BroadcastReceiver {
+            name: format!(
+                "{} (subscriber {})",
+                locked_hub.name, locked_hub.subscription_count
+            ),
+            size: locked_hub.size,
+            hub: Arc::clone(hub),
+            subscription,
+            supply,
+        }
+    }
+}
+
+/// The [`Producer`] of a [`BroadcastChannel`].
+///
+/// Each good is cloned to every subscribed [`BroadcastReceiver`].
+#[derive(Debug)]
+pub struct BroadcastSender<G> {
+    /// The name of the sender.
+    name: CloneName,
+    /// The state of the channel.
+    hub: Arc<Mutex<Hub<G>>>,
+    /// Held by every sender so that receivers know when all senders have been dropped.
+    supply: Arc<()>,
+}
+
+impl<G> BroadcastSender<G> {
+    /// Creates a new [`BroadcastReceiver`] that consumes every good produced after this call.
+    pub fn subscribe(&self) -> BroadcastReceiver<G> {
+        Hub::subscribe(&self.hub, Arc::downgrade(&self.supply))
+    }
+}
+
+impl<G> Clone for BroadcastSender<G> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            hub: Arc::clone(&self.hub),
+            supply: Arc::clone(&self.supply),
+        }
+    }
+}
+
+impl<G> Agent for BroadcastSender<G> {
+    type Good = G;
+}
+
+impl<G> Display for BroadcastSender<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G: Clone> Producer for BroadcastSender<G> {
+    type Flaws = WithdrawnDemand;
+
+    /// Stores a clone of `good` for every subscribed [`BroadcastReceiver`].
+    ///
+    /// If the subscription of a [`BroadcastReceiver`] is full, its oldest good is evicted.
+    ///
+    /// # Errors
+    ///
+    /// If no [`BroadcastReceiver`] is subscribed, throws [`WithdrawnDemand`].
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        let mut hub = lock(&self.hub);
+        let size = hub.size;
+        hub.subscriptions
+            .retain(|subscription| subscription.strong_count() > 0);
+
+        if hub.subscriptions.is_empty() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
+        for subscription in hub.subscriptions.iter().filter_map(Weak::upgrade) {
+            let mut locked_subscription = lock(&subscription);
+
+            if locked_subscription.goods.len() >= size {
+                drop(locked_subscription.goods.pop_front());
+                locked_subscription.missed = locked_subscription.missed.saturating_add(1);
+            }
+
+            locked_subscription.goods.push_back(good.clone());
+        }
+    }
+}
+
+/// A [`Consumer`] subscribed to a [`BroadcastChannel`].
+///
+/// A receiver is unsubscribed when it is dropped.
+#[derive(Debug)]
+pub struct BroadcastReceiver<G> {
+    /// The name of the receiver.
+    name: String,
+    /// The capacity of the subscription.
+    size: usize,
+    /// The state of the channel.
+    hub: Arc<Mutex<Hub<G>>>,
+    /// The goods stored for `self`.
+    subscription: Arc<Mutex<Subscription<G>>>,
+    /// Refers to the item held by every sender of the channel.
+    supply: Weak<()>,
+}
+
+impl<G> BroadcastReceiver<G> {
+    /// Creates a new [`BroadcastReceiver`] that consumes every good produced after this call.
+    #[must_use]
+    pub fn subscribe(&self) -> Self {
+        Hub::subscribe(&self.hub, Weak::clone(&self.supply))
+    }
+}
+
+impl<G> Agent for BroadcastReceiver<G> {
+    type Good = G;
+}
+
+impl<G> Consumer for BroadcastReceiver<G> {
+    type Flaws = ConsumptionFlaws<BroadcastDefect>;
+
+    /// Retrieves the oldest good stored for `self`.
+    ///
+    /// # Errors
+    ///
+    /// If goods were evicted since the last consumption, throws [`BroadcastDefect::Lagged`] once before consuming the remaining goods. If no goods are stored and all senders have been dropped, throws [`BroadcastDefect::Withdrawn`]. If no goods are stored otherwise, throws [`EmptyStock`].
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let mut subscription = lock(&self.subscription);
+
+        if subscription.missed > 0 {
+            let missed = subscription.missed;
+            subscription.missed = 0;
+            throw!(self.failure(Fault::Defect(BroadcastDefect::Lagged(missed))));
+        }
+
+        if let Some(good) = subscription.goods.pop_front() {
+            good
+        } else if self.supply.strong_count() == 0 {
+            throw!(self.failure(Fault::Defect(BroadcastDefect::Withdrawn)));
+        } else {
+            throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+        }
+    }
+}
+
+impl<G> Display for BroadcastReceiver<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Inventory for BroadcastReceiver<G> {
+    fn len(&self) -> usize {
+        lock(&self.subscription).goods.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(self.size)
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.supply.strong_count() == 0
+    }
+}
+
+/// A channel that delivers a clone of each good to every subscribed [`BroadcastReceiver`].
+///
+/// Each [`BroadcastReceiver`] stores up to a fixed number of goods. When a [`BroadcastReceiver`] falls behind, its oldest goods are evicted and it throws [`BroadcastDefect::Lagged`] with the number of goods it missed.
+#[derive(Debug)]
+pub struct BroadcastChannel<G> {
+    /// The type of the good that is exchanged on the channel.
+    good: PhantomData<G>,
+}
+
+impl<G> BroadcastChannel<G> {
+    /// Creates a [`BroadcastSender`] and a subscribed [`BroadcastReceiver`] of a channel where each subscription has a capacity of `size`.
+    ///
+    /// Because a subscription without capacity has no good to evict, a `size` of 0 is treated as 1.
+    pub fn establish<S>(name_str: &S, size: usize) -> (BroadcastSender<G>, BroadcastReceiver<G>)
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        let sender = BroadcastSender {
+            name: CloneName::new(name_str.as_ref()),
+            hub: Arc::new(Mutex::new(Hub {
+                name: String::from(name_str.as_ref()),
+                size: cmp::max(size, 1),
+                subscription_count: 0,
+                subscriptions: Vec::new(),
+            })),
+            supply: Arc::new(()),
+        };
+        let receiver = sender.subscribe();
+
+        (sender, receiver)
+    }
+}
@@ -1,26 +1,195 @@
 //! Implements [`Producer`] and [`Consumer`] for channels implemented by [`crossbeam`].
 use {
-    alloc::string::String,
+    crate::{
+        clone_name::CloneName, inventory::Inventory, presence::Presence, select::SelectDefect,
+        sync::lock,
+    },
+    alloc::{string::String, sync::Arc, vec::Vec},
     core::{
+        cmp,
         fmt::{self, Display, Formatter},
         marker::PhantomData,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
     },
-    crossbeam_channel::{Receiver, Sender, TryRecvError, TrySendError},
-    fehler::throws,
+    crossbeam_channel::{Receiver, Select, SelectedOperation, Sender, TryRecvError, TrySendError},
+    fehler::{throw, throws},
     market::{
         channel::{FiniteChannel, InfiniteChannel, WithdrawnDemand, WithdrawnSupply},
         Agent, Consumer, ConsumptionFlaws, EmptyStock, Failure, Fault, FullStock, Producer,
         ProductionFlaws, Recall,
     },
+    std::{sync::Mutex, thread, time::Instant},
 };
 
+/// Signals the agents of a channel that are blocked when the channel is closed.
+///
+/// The signal is a channel that never carries a message; closing drops its only [`Sender`], which makes every receive on it ready. So a blocked agent selects on the signal alongside its own operation.
+#[derive(Debug)]
+struct CloseSignal {
+    /// The only [`Sender`] of the signal, which is taken when the channel is closed.
+    trigger: Arc<Mutex<Option<Sender<()>>>>,
+    /// Ready once the channel is closed.
+    receiver: Receiver<()>,
+}
+
+impl CloseSignal {
+    /// Creates a new [`CloseSignal`].
+    fn new() -> Self {
+        let (sender, receiver) = crossbeam_channel::bounded(0);
+
+        Self {
+            trigger: Arc::new(Mutex::new(Some(sender))),
+            receiver,
+        }
+    }
+
+    /// Wakes every agent that is blocked on `self`.
+    fn trigger(&self) {
+        drop(lock(&self.trigger).take());
+    }
+
+    /// Adds a receive operation on `self` to `select` and returns its index.
+    fn select<'a>(&'a self, select: &mut Select<'a>) -> usize {
+        select.recv(&self.receiver)
+    }
+
+    /// Completes `operation`, which was selected from the operation added by [`CloseSignal::select()`].
+    fn complete(&self, operation: SelectedOperation<'_>) {
+        // The signal never carries a message, so the receive only completes with an error.
+        let _ = operation.recv(&self.receiver);
+    }
+}
+
+impl Clone for CloseSignal {
+    fn clone(&self) -> Self {
+        Self {
+            trigger: Arc::clone(&self.trigger),
+            receiver: self.receiver.clone(),
+        }
+    }
+}
+
 /// Implements [`Consumer`] for goods of type `G` from a crossbeam channel.
 #[derive(Debug)]
 pub struct CrossbeamReceiver<G> {
     /// Describes the channel.
-    name: String,
+    name: CloneName,
     /// The [`Receiver`] of the channel.
     receiver: Receiver<G>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+    /// Wakes the blocked agents of the channel when it is closed.
+    close_signal: CloseSignal,
+}
+
+impl<G> CrossbeamReceiver<G> {
+    /// Closes the channel to consumption.
+    ///
+    /// After `self` is closed, every producer of the channel throws [`WithdrawnDemand`], and every consumer of the channel, including `self` and its clones, consumes the remaining goods and then throws [`WithdrawnSupply`].
+    pub fn close(&self) {
+        self.presence.close();
+        self.close_signal.trigger();
+    }
+
+    /// Retrieves up to `limit` goods from the channel without blocking.
+    ///
+    /// The returned [`Vec`] is empty if the channel has no goods.
+    ///
+    /// # Errors
+    ///
+    /// If no goods are retrieved because the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_batch(&self, limit: usize) -> Vec<G> {
+        let mut goods: Vec<G> = self.receiver.try_iter().take(limit).collect();
+
+        if goods.is_empty() && limit != 0 {
+            // Consumes once more so that an ended channel throws its defect only after its last good is retrieved.
+            match self.consume() {
+                Ok(good) => goods.push(good),
+                Err(failure) => {
+                    if failure.is_defect() {
+                        throw!(failure);
+                    }
+                }
+            }
+        }
+
+        goods
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available.
+    ///
+    /// # Errors
+    ///
+    /// If the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_wait(&self) -> G {
+        self.consume_until(None)?
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws an [`EmptyStock`] insufficiency; if the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_timeout(&self, timeout: Duration) -> G {
+        self.consume_until(Instant::now().checked_add(timeout))?
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available or `deadline` is reached.
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws an [`EmptyStock`] insufficiency; if the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_deadline(&self, deadline: Instant) -> G {
+        self.consume_until(Some(deadline))?
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available, the channel is closed, or `deadline`, if any, is reached.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    fn consume_until(&self, deadline: Option<Instant>) -> G {
+        loop {
+            if self.presence.is_ended() {
+                break self.consume()?;
+            }
+
+            let mut select = Select::new();
+            let receive = select.recv(&self.receiver);
+            let _ = self.close_signal.select(&mut select);
+            let operation = match wait(&mut select, deadline) {
+                Some(operation) => operation,
+                None => throw!(self.failure(Fault::Insufficiency(EmptyStock::default()))),
+            };
+
+            if operation.index() == receive {
+                break operation
+                    .recv(&self.receiver)
+                    .map_err(|_| self.failure(Fault::Defect(WithdrawnSupply::default())))?;
+            }
+
+            // The channel was closed, which is observed by the next iteration.
+            self.close_signal.complete(operation);
+        }
+    }
+
+    /// Returns if the channel has no goods and will not be supplied with more goods.
+    fn is_exhausted(&self) -> bool {
+        self.presence.is_ended() && self.receiver.is_empty()
+    }
+}
+
+impl<G> Clone for CrossbeamReceiver<G> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            receiver: self.receiver.clone(),
+            presence: self.presence.clone(),
+            close_signal: self.close_signal.clone(),
+        }
+    }
 }
 
 impl<G> Agent for CrossbeamReceiver<G> {
@@ -33,7 +202,13 @@ impl<G> Consumer for CrossbeamReceiver<G> {
     #[throws(Failure<Self::Flaws>)]
     fn consume(&self) -> Self::Good {
         self.receiver.try_recv().map_err(|error| match error {
-            TryRecvError::Empty => self.failure(Fault::Insufficiency(EmptyStock::default())),
+            TryRecvError::Empty => {
+                if self.presence.is_ended() {
+                    self.failure(Fault::Defect(WithdrawnSupply::default()))
+                } else {
+                    self.failure(Fault::Insufficiency(EmptyStock::default()))
+                }
+            }
             TryRecvError::Disconnected => self.failure(Fault::Defect(WithdrawnSupply::default())),
         })?
     }
@@ -45,13 +220,132 @@ impl<G> Display for CrossbeamReceiver<G> {
     }
 }
 
+impl<G> Inventory for CrossbeamReceiver<G> {
+    fn len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.receiver.capacity()
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.presence.is_withdrawn()
+    }
+}
+
 /// The [`Producer`] for the implementation of [`FiniteChannel`] for [`crossbeam`].
 #[derive(Debug)]
 pub struct CrossbeamFiniteSender<G> {
     /// Describes the channel.
-    name: String,
+    name: CloneName,
     /// The sender.
     sender: Sender<G>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+    /// Wakes the blocked agents of the channel when it is closed.
+    close_signal: CloseSignal,
+}
+
+impl<G> CrossbeamFiniteSender<G> {
+    /// Closes the channel to production.
+    ///
+    /// After `self` is closed, every producer of the channel, including `self` and its clones, throws [`WithdrawnDemand`], and every consumer of the channel consumes the remaining goods and then throws [`WithdrawnSupply`].
+    pub fn close(&self) {
+        self.presence.close();
+        self.close_signal.trigger();
+    }
+
+    /// Stores the goods of `goods` in the channel until one is recalled because the channel is full, has no consumers or has been closed.
+    ///
+    /// Returns the goods that were not stored in their original order, which is empty if all goods were stored.
+    pub fn produce_batch<I: IntoIterator<Item = G>>(&self, goods: I) -> Vec<G> {
+        let mut goods = goods.into_iter();
+        let mut remainder = Vec::new();
+
+        if !self.presence.is_ended() {
+            for good in goods.by_ref() {
+                if let Err(error) = self.sender.try_send(good) {
+                    remainder.push(error.into_inner());
+                    break;
+                }
+            }
+        }
+
+        remainder.extend(goods);
+        remainder
+    }
+
+    /// Stores `good` into the channel, blocking until stock is available.
+    ///
+    /// # Errors
+    ///
+    /// If the channel has no consumers or has been closed, throws a [`WithdrawnDemand`] defect.
+    #[throws(Recall<ProductionFlaws<WithdrawnDemand>, G>)]
+    pub fn produce_wait(&self, good: G) {
+        self.produce_until(good, None)?;
+    }
+
+    /// Stores `good` into the channel, blocking until stock is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws a [`FullStock`] insufficiency; if the channel has no consumers or has been closed, throws a [`WithdrawnDemand`] defect.
+    #[throws(Recall<ProductionFlaws<WithdrawnDemand>, G>)]
+    pub fn produce_timeout(&self, good: G, timeout: Duration) {
+        self.produce_until(good, Instant::now().checked_add(timeout))?;
+    }
+
+    /// Stores `good` into the channel, blocking until stock is available or `deadline` is reached.
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws a [`FullStock`] insufficiency; if the channel has no consumers or has been closed, throws a [`WithdrawnDemand`] defect.
+    #[throws(Recall<ProductionFlaws<WithdrawnDemand>, G>)]
+    pub fn produce_deadline(&self, good: G, deadline: Instant) {
+        self.produce_until(good, Some(deadline))?;
+    }
+
+    /// Stores `good` into the channel, blocking until stock is available, the channel is closed, or `deadline`, if any, is reached.
+    #[throws(Recall<ProductionFlaws<WithdrawnDemand>, G>)]
+    fn produce_until(&self, good: G, deadline: Option<Instant>) {
+        loop {
+            if self.presence.is_ended() {
+                throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+            }
+
+            let mut select = Select::new();
+            let send = select.send(&self.sender);
+            let _ = self.close_signal.select(&mut select);
+            let operation = match wait(&mut select, deadline) {
+                Some(operation) => operation,
+                None => throw!(self.recall(Fault::Insufficiency(FullStock::default()), good)),
+            };
+
+            if operation.index() == send {
+                break operation.send(&self.sender, good).map_err(|error| {
+                    self.recall(
+                        Fault::Defect(WithdrawnDemand::default()),
+                        error.into_inner(),
+                    )
+                })?;
+            }
+
+            // The channel was closed, which is observed by the next iteration.
+            self.close_signal.complete(operation);
+        }
+    }
+}
+
+impl<G> Clone for CrossbeamFiniteSender<G> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            sender: self.sender.clone(),
+            presence: self.presence.clone(),
+            close_signal: self.close_signal.clone(),
+        }
+    }
 }
 
 impl<G> Agent for CrossbeamFiniteSender<G> {
@@ -64,11 +358,29 @@ impl<G> Display for CrossbeamFiniteSender<G> {
     }
 }
 
+impl<G> Inventory for CrossbeamFiniteSender<G> {
+    fn len(&self) -> usize {
+        self.sender.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.sender.capacity()
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.presence.is_withdrawn()
+    }
+}
+
 impl<G> Producer for CrossbeamFiniteSender<G> {
     type Flaws = ProductionFlaws<WithdrawnDemand>;
 
     #[throws(Recall<Self::Flaws, Self::Good>)]
     fn produce(&self, good: Self::Good) {
+        if self.presence.is_ended() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
         self.sender.try_send(good).map_err(|error| match error {
             TrySendError::Full(good) => {
                 self.recall(Fault::Insufficiency(FullStock::default()), good)
@@ -84,9 +396,54 @@ impl<G> Producer for CrossbeamFiniteSender<G> {
 #[derive(Debug)]
 pub struct CrossbeamInfiniteSender<G> {
     /// The name of the sender.
-    name: String,
+    name: CloneName,
     /// The sender.
     sender: Sender<G>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+    /// Wakes the blocked agents of the channel when it is closed.
+    close_signal: CloseSignal,
+}
+
+impl<G> CrossbeamInfiniteSender<G> {
+    /// Closes the channel to production.
+    ///
+    /// After `self` is closed, every producer of the channel, including `self` and its clones, throws [`WithdrawnDemand`], and every consumer of the channel consumes the remaining goods and then throws [`WithdrawnSupply`].
+    pub fn close(&self) {
+        self.presence.close();
+        self.close_signal.trigger();
+    }
+
+    /// Stores the goods of `goods` in the channel until one is recalled because the channel has no consumers or has been closed.
+    ///
+    /// Returns the goods that were not stored in their original order, which is empty if all goods were stored.
+    pub fn produce_batch<I: IntoIterator<Item = G>>(&self, goods: I) -> Vec<G> {
+        let mut goods = goods.into_iter();
+        let mut remainder = Vec::new();
+
+        if !self.presence.is_ended() {
+            for good in goods.by_ref() {
+                if let Err(error) = self.sender.send(good) {
+                    remainder.push(error.into_inner());
+                    break;
+                }
+            }
+        }
+
+        remainder.extend(goods);
+        remainder
+    }
+}
+
+impl<G> Clone for CrossbeamInfiniteSender<G> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            sender: self.sender.clone(),
+            presence: self.presence.clone(),
+            close_signal: self.close_signal.clone(),
+        }
+    }
 }
 
 impl<G> Agent for CrossbeamInfiniteSender<G> {
@@ -99,11 +456,29 @@ impl<G> Display for CrossbeamInfiniteSender<G> {
     }
 }
 
+impl<G> Inventory for CrossbeamInfiniteSender<G> {
+    fn len(&self) -> usize {
+        self.sender.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.sender.capacity()
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.presence.is_withdrawn()
+    }
+}
+
 impl<G> Producer for CrossbeamInfiniteSender<G> {
     type Flaws = WithdrawnDemand;
 
     #[throws(Recall<Self::Flaws, Self::Good>)]
     fn produce(&self, good: Self::Good) {
+        if self.presence.is_ended() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
         self.sender.send(good).map_err(|error| {
             self.recall(
                 Fault::Defect(WithdrawnDemand::default()),
@@ -128,14 +503,23 @@ impl<G> InfiniteChannel<G> for CrossbeamInfiniteChannel<G> {
     where
         S: AsRef<str> + ?Sized,
     {
-        let name = String::from(name_str.as_ref());
+        let name = name_str.as_ref();
+        let (sender_presence, receiver_presence) = Presence::pair();
+        let close_signal = CloseSignal::new();
         let (sender, receiver) = crossbeam_channel::unbounded();
         (
             CrossbeamInfiniteSender {
-                name: name.clone(),
+                name: CloneName::new(name),
                 sender,
+                presence: sender_presence,
+                close_signal: close_signal.clone(),
+            },
+            CrossbeamReceiver {
+                name: CloneName::new(name),
+                receiver,
+                presence: receiver_presence,
+                close_signal,
             },
-            CrossbeamReceiver { name, receiver },
         )
     }
 }
@@ -155,14 +539,436 @@ impl<G> FiniteChannel<G> for CrossbeamFiniteChannel<G> {
     where
         S: AsRef<str> + ?Sized,
     {
-        let name = String::from(name_str.as_ref());
+        let name = name_str.as_ref();
+        let (sender_presence, receiver_presence) = Presence::pair();
+        let close_signal = CloseSignal::new();
         let (sender, receiver) = crossbeam_channel::bounded(size);
         (
             CrossbeamFiniteSender {
-                name: name.clone(),
+                name: CloneName::new(name),
+                sender,
+                presence: sender_presence,
+                close_signal: close_signal.clone(),
+            },
+            CrossbeamReceiver {
+                name: CloneName::new(name),
+                receiver,
+                presence: receiver_presence,
+                close_signal,
+            },
+        )
+    }
+}
+
+/// A channel implemented by [`crossbeam`] that has no capacity.
+///
+/// A good is only produced when a consumer is waiting to consume it, so a producer that does not wait, such as [`Producer::produce()`], throws [`FullStock`] unless a consumer is blocked in a call such as [`CrossbeamReceiver::consume_wait()`]. This is the same channel created by [`CrossbeamFiniteChannel`] with a size of 0.
+#[derive(Debug)]
+pub struct CrossbeamRendezvousChannel<G> {
+    /// The type of the good that is exchanged on the channel.
+    good: PhantomData<G>,
+}
+
+impl<G> CrossbeamRendezvousChannel<G> {
+    /// Creates the [`Producer`] and [`Consumer`] connected to a channel with no capacity.
+    pub fn establish<S>(name_str: &S) -> (CrossbeamFiniteSender<G>, CrossbeamReceiver<G>)
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        CrossbeamFiniteChannel::establish(name_str, 0)
+    }
+}
+
+/// The [`Producer`] for [`CrossbeamSlidingChannel`].
+///
+/// When the channel is full, the oldest good in the channel is evicted to make stock available.
+#[derive(Debug)]
+pub struct CrossbeamSlidingSender<G> {
+    /// Describes the channel.
+    name: CloneName,
+    /// The sender.
+    sender: Sender<G>,
+    /// Evicts goods from the channel.
+    evictor: Receiver<G>,
+    /// The number of goods that have been evicted from the channel.
+    eviction_count: Arc<AtomicUsize>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+    /// Wakes the blocked agents of the channel when it is closed.
+    close_signal: CloseSignal,
+}
+
+impl<G> CrossbeamSlidingSender<G> {
+    /// Closes the channel to production.
+    ///
+    /// After `self` is closed, every producer of the channel, including `self` and its clones, throws [`WithdrawnDemand`], and every consumer of the channel consumes the remaining goods and then throws [`WithdrawnSupply`].
+    pub fn close(&self) {
+        self.presence.close();
+        self.close_signal.trigger();
+    }
+
+    /// Returns the number of goods that have been evicted from the channel.
+    pub fn eviction_count(&self) -> usize {
+        self.eviction_count.load(Ordering::Relaxed)
+    }
+
+    /// Stores the goods of `goods` in the channel, evicting the oldest goods as needed, until one is recalled because the channel has no consumers or has been closed.
+    ///
+    /// Returns the goods that were not stored in their original order, which is empty if all goods were stored.
+    pub fn produce_batch<I: IntoIterator<Item = G>>(&self, goods: I) -> Vec<G> {
+        let mut goods = goods.into_iter();
+        let mut remainder = Vec::new();
+
+        if !self.presence.is_ended() {
+            for good in goods.by_ref() {
+                if let Err(disconnected_good) = self.send(good) {
+                    remainder.push(disconnected_good);
+                    break;
+                }
+            }
+        }
+
+        remainder.extend(goods);
+        remainder
+    }
+
+    /// Stores `good` in the channel, evicting the oldest good if the channel is full.
+    ///
+    /// Returns `good` if the channel has no consumers.
+    fn send(&self, mut good: G) -> Result<(), G> {
+        loop {
+            match self.sender.try_send(good) {
+                Ok(()) => break Ok(()),
+                Err(TrySendError::Full(full_good)) => {
+                    // The evicted good may not be the oldest if a consumer consumed between the send and the eviction, but stock is made available either way.
+                    if self.evictor.try_recv().is_ok() {
+                        let _ = self.eviction_count.fetch_add(1, Ordering::Relaxed);
+                    }
+
+                    good = full_good;
+                }
+                Err(TrySendError::Disconnected(disconnected_good)) => break Err(disconnected_good),
+            }
+        }
+    }
+}
+
+impl<G> Clone for CrossbeamSlidingSender<G> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            sender: self.sender.clone(),
+            evictor: self.evictor.clone(),
+            eviction_count: Arc::clone(&self.eviction_count),
+            presence: self.presence.clone(),
+            close_signal: self.close_signal.clone(),
+        }
+    }
+}
+
+impl<G> Agent for CrossbeamSlidingSender<G> {
+    type Good = G;
+}
+
+impl<G> Display for CrossbeamSlidingSender<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Inventory for CrossbeamSlidingSender<G> {
+    fn len(&self) -> usize {
+        self.sender.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.sender.capacity()
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.presence.is_withdrawn()
+    }
+}
+
+impl<G> Producer for CrossbeamSlidingSender<G> {
+    type Flaws = WithdrawnDemand;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        if self.presence.is_ended() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
+        if let Err(disconnected_good) = self.send(good) {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), disconnected_good));
+        }
+    }
+}
+
+/// A channel implemented by [`crossbeam`] with a limited capacity that evicts its oldest good instead of rejecting a new good.
+///
+/// This is intended for streams where only the latest goods are relevant, such as telemetry. The number of evicted goods is reported by [`CrossbeamSlidingSender::eviction_count()`].
+#[derive(Debug)]
+pub struct CrossbeamSlidingChannel<G> {
+    /// The type of the good that is exchanged on the channel.
+    good: PhantomData<G>,
+}
+
+impl<G> CrossbeamSlidingChannel<G> {
+    /// Creates the [`Producer`] and [`Consumer`] connected to a channel with capacity of `size`.
+    ///
+    /// Because a channel without capacity has no good to evict, a `size` of 0 is treated as 1.
+    pub fn establish<S>(
+        name_str: &S,
+        size: usize,
+    ) -> (CrossbeamSlidingSender<G>, CrossbeamReceiver<G>)
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        let name = name_str.as_ref();
+        let (sender_presence, receiver_presence) = Presence::pair();
+        let close_signal = CloseSignal::new();
+        let (sender, receiver) = crossbeam_channel::bounded(cmp::max(size, 1));
+        (
+            CrossbeamSlidingSender {
+                name: CloneName::new(name),
                 sender,
+                evictor: receiver.clone(),
+                eviction_count: Arc::new(AtomicUsize::new(0)),
+                presence: sender_presence,
+                close_signal: close_signal.clone(),
+            },
+            CrossbeamReceiver {
+                name: CloneName::new(name),
+                receiver,
+                presence: receiver_presence,
+                close_signal,
             },
-            CrossbeamReceiver { name, receiver },
         )
     }
 }
+
+/// A [`Consumer`] that consumes goods of type `G` from whichever of multiple [`CrossbeamReceiver`]s is ready first.
+///
+/// Each good is consumed along with the index of the [`CrossbeamReceiver`] that supplied it.
+#[derive(Debug)]
+pub struct CrossbeamSelector<G> {
+    /// The name of the selector.
+    name: String,
+    /// The [`CrossbeamReceiver`]s.
+    receivers: Vec<CrossbeamReceiver<G>>,
+}
+
+impl<G> CrossbeamSelector<G> {
+    /// Creates a new, empty [`CrossbeamSelector`].
+    #[must_use]
+    pub fn new<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            receivers: Vec::new(),
+        }
+    }
+
+    /// Adds `receiver` to the end of the [`CrossbeamReceiver`]s held by `self` and returns its index.
+    pub fn push(&mut self, receiver: CrossbeamReceiver<G>) -> usize {
+        self.receivers.push(receiver);
+        self.receivers.len() - 1
+    }
+
+    /// Removes the [`CrossbeamReceiver`] at `index` from `self` and returns it.
+    ///
+    /// The index of each [`CrossbeamReceiver`] after `index` decreases by 1.
+    pub fn remove(&mut self, index: usize) -> Option<CrossbeamReceiver<G>> {
+        if index < self.receivers.len() {
+            Some(self.receivers.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the next good from the first ready [`CrossbeamReceiver`], blocking until one is available.
+    ///
+    /// If `self` holds no [`CrossbeamReceiver`]s, this blocks forever.
+    ///
+    /// # Errors
+    ///
+    /// If no [`CrossbeamReceiver`] has a good and a [`CrossbeamReceiver`] is empty and has no producers or has been closed, throws a [`SelectDefect`] of [`WithdrawnSupply`] with its index.
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<WithdrawnSupply>>>)]
+    pub fn consume_wait(&self) -> (usize, G) {
+        self.consume_until(None)?
+    }
+
+    /// Retrieves the next good from the first ready [`CrossbeamReceiver`], blocking until one is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws an [`EmptyStock`] insufficiency; if no [`CrossbeamReceiver`] has a good and a [`CrossbeamReceiver`] is empty and has no producers or has been closed, throws a [`SelectDefect`] of [`WithdrawnSupply`] with its index.
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<WithdrawnSupply>>>)]
+    pub fn consume_timeout(&self, timeout: Duration) -> (usize, G) {
+        self.consume_until(Instant::now().checked_add(timeout))?
+    }
+
+    /// Retrieves the next good from the first ready [`CrossbeamReceiver`], blocking until one is available or `deadline` is reached.
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws an [`EmptyStock`] insufficiency; if no [`CrossbeamReceiver`] has a good and a [`CrossbeamReceiver`] is empty and has no producers or has been closed, throws a [`SelectDefect`] of [`WithdrawnSupply`] with its index.
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<WithdrawnSupply>>>)]
+    pub fn consume_deadline(&self, deadline: Instant) -> (usize, G) {
+        self.consume_until(Some(deadline))?
+    }
+
+    /// Retrieves the next good from the first ready [`CrossbeamReceiver`], blocking until one is available, a [`CrossbeamReceiver`] is closed, or `deadline`, if any, is reached.
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<WithdrawnSupply>>>)]
+    fn consume_until(&self, deadline: Option<Instant>) -> (usize, G) {
+        loop {
+            match self.consume() {
+                Ok(selection) => break selection,
+                Err(failure) => {
+                    if failure.is_defect() {
+                        throw!(failure);
+                    }
+                }
+            }
+
+            // Every receiver that is ended is either exhausted or has goods, so its close signal is not needed.
+            let (mut select, ready) = self.select();
+
+            if ready.is_empty() {
+                if self.receivers.is_empty() {
+                    idle(deadline);
+                    throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+                }
+
+                // A receiver became exhausted after it was consumed from, which is observed by the next iteration.
+                continue;
+            }
+            let closable: Vec<&CrossbeamReceiver<G>> = ready
+                .iter()
+                .map(|&(_, receiver)| receiver)
+                .filter(|receiver| !receiver.presence.is_ended())
+                .collect();
+
+            for receiver in &closable {
+                let _ = receiver.close_signal.select(&mut select);
+            }
+
+            let operation = match wait(&mut select, deadline) {
+                Some(operation) => operation,
+                None => throw!(self.failure(Fault::Insufficiency(EmptyStock::default()))),
+            };
+
+            if let Some(&(index, receiver)) = ready.get(operation.index()) {
+                break self.complete(operation, index, receiver)?;
+            }
+
+            // A receiver was closed, which is observed by the next iteration.
+            if let Some(receiver) = operation
+                .index()
+                .checked_sub(ready.len())
+                .and_then(|signal| closable.get(signal))
+            {
+                receiver.close_signal.complete(operation);
+            }
+        }
+    }
+
+    /// Returns a [`Select`] with a receive operation for each [`CrossbeamReceiver`] that is not exhausted, along with the index of the [`CrossbeamReceiver`] of each operation.
+    fn select(&self) -> (Select<'_>, Vec<(usize, &CrossbeamReceiver<G>)>) {
+        let mut select = Select::new();
+        let mut ready = Vec::new();
+
+        for (index, receiver) in self.receivers.iter().enumerate() {
+            if !receiver.is_exhausted() {
+                let _ = select.recv(&receiver.receiver);
+                ready.push((index, receiver));
+            }
+        }
+
+        (select, ready)
+    }
+
+    /// Completes the receive of `operation` from `receiver`, which is at `index`.
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<WithdrawnSupply>>>)]
+    fn complete(
+        &self,
+        operation: SelectedOperation<'_>,
+        index: usize,
+        receiver: &CrossbeamReceiver<G>,
+    ) -> (usize, G) {
+        (
+            index,
+            operation.recv(&receiver.receiver).map_err(|_| {
+                self.failure(Fault::Defect(SelectDefect::new(
+                    index,
+                    WithdrawnSupply::default(),
+                )))
+            })?,
+        )
+    }
+}
+
+impl<G> Agent for CrossbeamSelector<G> {
+    type Good = (usize, G);
+}
+
+impl<G> Consumer for CrossbeamSelector<G> {
+    type Flaws = ConsumptionFlaws<SelectDefect<WithdrawnSupply>>;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let (mut select, ready) = self.select();
+
+        if let Ok(operation) = select.try_select() {
+            if let Some(&(index, receiver)) = ready.get(operation.index()) {
+                return self.complete(operation, index, receiver)?;
+            }
+        }
+
+        if let Some(index) = self
+            .receivers
+            .iter()
+            .position(CrossbeamReceiver::is_exhausted)
+        {
+            throw!(self.failure(Fault::Defect(SelectDefect::new(
+                index,
+                WithdrawnSupply::default()
+            ))));
+        }
+
+        throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+    }
+}
+
+impl<G> Display for CrossbeamSelector<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Blocks until `deadline` is reached, or forever if there is no `deadline`.
+fn idle(deadline: Option<Instant>) {
+    if let Some(time) = deadline {
+        thread::sleep(time.saturating_duration_since(Instant::now()));
+    } else {
+        loop {
+            thread::park();
+        }
+    }
+}
+
+/// Blocks until an operation of `select` is ready or `deadline`, if any, is reached.
+///
+/// Returns the selected operation, or [`None`] if `deadline` is reached.
+fn wait<'a>(select: &mut Select<'a>, deadline: Option<Instant>) -> Option<SelectedOperation<'a>> {
+    if let Some(time) = deadline {
+        select
+            .select_timeout(time.saturating_duration_since(Instant::now()))
+            .ok()
+    } else {
+        Some(select.select())
+    }
+}
@@ -1,26 +1,178 @@
 //! Implementation of [`Channel`] for [`std::sync::mpsc`].
+//!
+//! Each message of a channel holds either a good or nothing, where a message holding nothing is sent when a producer closes the channel so that a consumer blocked receiving observes the close.
 use {
-    alloc::string::String,
+    crate::{clone_name::CloneName, inventory::Inventory, presence::Presence},
+    alloc::{string::String, sync::Arc, vec::Vec},
     core::{
+        convert::TryFrom,
         fmt::{self, Display, Formatter},
         marker::PhantomData,
+        sync::atomic::{AtomicBool, AtomicIsize, Ordering},
+        time::Duration,
     },
-    fehler::throws,
+    fehler::{throw, throws},
     market::{
         channel::{FiniteChannel, InfiniteChannel, WithdrawnDemand, WithdrawnSupply},
         Agent, Consumer, ConsumptionFlaws, EmptyStock, Failure, Fault, FullStock, Producer,
         ProductionFlaws, Recall,
     },
-    std::sync::mpsc::{self, Receiver, Sender, SyncSender, TryRecvError, TrySendError},
+    std::{
+        sync::mpsc::{
+            self, Receiver, RecvTimeoutError, SendError, Sender, SyncSender, TryRecvError,
+            TrySendError,
+        },
+        thread,
+        time::Instant,
+    },
 };
 
+/// The outcome of an attempt by [`StdSyncSender`] to store a good of type `G`.
+type Attempt<G> = Result<Result<(), Recall<ProductionFlaws<WithdrawnDemand>, G>>, G>;
+
 /// Implements [`Consumer`] for goods of type `G` from a channel created by [`mpsc`].
 #[derive(Debug)]
 pub struct StdReceiver<G> {
     /// Describes the channel.
     name: String,
     /// The [`Receiver`] of the channel.
-    receiver: Receiver<G>,
+    receiver: Receiver<Option<G>>,
+    /// The number of goods stored in the channel.
+    ///
+    /// [`Receiver`] does not provide its length so the agents of the channel track it. A production is recorded after its good is sent, so the stock is briefly negative if the good is received first.
+    stock: Arc<AtomicIsize>,
+    /// The capacity of the channel.
+    capacity: Option<usize>,
+    /// If `self` is blocked receiving from the channel or is preparing to block.
+    is_waiting: Arc<AtomicBool>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+}
+
+impl<G> StdReceiver<G> {
+    /// Closes the channel to consumption.
+    ///
+    /// After `self` is closed, every producer of the channel throws [`WithdrawnDemand`], and `self` consumes the remaining goods and then throws [`WithdrawnSupply`].
+    pub fn close(&self) {
+        self.presence.close();
+    }
+
+    /// Retrieves up to `limit` goods from the channel without blocking.
+    ///
+    /// The returned [`Vec`] is empty if the channel has no goods.
+    ///
+    /// # Errors
+    ///
+    /// If no goods are retrieved because the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_batch(&self, limit: usize) -> Vec<G> {
+        // Messages holding nothing are skipped since the close they signal is observed when the channel is empty.
+        let mut goods: Vec<G> = self.receiver.try_iter().flatten().take(limit).collect();
+
+        if goods.is_empty() {
+            if limit != 0 {
+                // Consumes once more so that an ended channel throws its defect only after its last good is retrieved.
+                match self.consume() {
+                    Ok(good) => goods.push(good),
+                    Err(failure) => {
+                        if failure.is_defect() {
+                            throw!(failure);
+                        }
+                    }
+                }
+            }
+        } else {
+            self.remove_stock(goods.len());
+        }
+
+        goods
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available.
+    ///
+    /// # Errors
+    ///
+    /// If the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_wait(&self) -> G {
+        self.consume_until(None)?
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws an [`EmptyStock`] insufficiency; if the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_timeout(&self, timeout: Duration) -> G {
+        self.consume_until(Instant::now().checked_add(timeout))?
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available or `deadline` is reached.
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws an [`EmptyStock`] insufficiency; if the channel is empty and has no producers or has been closed, throws a [`WithdrawnSupply`] defect.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    pub fn consume_deadline(&self, deadline: Instant) -> G {
+        self.consume_until(Some(deadline))?
+    }
+
+    /// Retrieves the next good from the channel, blocking until one is available or `deadline`, if any, is reached.
+    #[throws(Failure<ConsumptionFlaws<WithdrawnSupply>>)]
+    fn consume_until(&self, deadline: Option<Instant>) -> G {
+        loop {
+            self.is_waiting.store(true, Ordering::SeqCst);
+            // A producer waiting on a channel without capacity is able to store its good once self is receiving.
+            self.presence.signal().notify();
+
+            // Checked after marking self as waiting so that a close is either observed here or wakes the receive.
+            let message = if self.presence.is_ended() {
+                None
+            } else if let Some(time) = deadline {
+                Some(
+                    self.receiver
+                        .recv_timeout(time.saturating_duration_since(Instant::now())),
+                )
+            } else {
+                Some(
+                    self.receiver
+                        .recv()
+                        .map_err(|_| RecvTimeoutError::Disconnected),
+                )
+            };
+
+            self.is_waiting.store(false, Ordering::SeqCst);
+
+            match message {
+                None => break self.consume()?,
+                Some(Ok(Some(good))) => break self.received(good),
+                // The channel was closed, which is observed by the next iteration.
+                Some(Ok(None)) => {}
+                Some(Err(RecvTimeoutError::Timeout)) => {
+                    throw!(self.failure(Fault::Insufficiency(EmptyStock::default())))
+                }
+                Some(Err(RecvTimeoutError::Disconnected)) => {
+                    throw!(self.failure(Fault::Defect(WithdrawnSupply::default())))
+                }
+            }
+        }
+    }
+
+    /// Records that `good` was removed from the stock of the channel and returns it.
+    fn received(&self, good: G) -> G {
+        self.remove_stock(1);
+        good
+    }
+
+    /// Records that `count` goods were removed from the stock of the channel.
+    fn remove_stock(&self, count: usize) {
+        let _ = self.stock.fetch_sub(
+            isize::try_from(count).unwrap_or(isize::MAX),
+            Ordering::Relaxed,
+        );
+        self.presence.signal().notify();
+    }
 }
 
 impl<G> Agent for StdReceiver<G> {
@@ -32,10 +184,23 @@ impl<G> Consumer for StdReceiver<G> {
 
     #[throws(Failure<Self::Flaws>)]
     fn consume(&self) -> Self::Good {
-        self.receiver.try_recv().map_err(|error| match error {
-            TryRecvError::Empty => self.failure(Fault::Insufficiency(EmptyStock::default())),
-            TryRecvError::Disconnected => self.failure(Fault::Defect(WithdrawnSupply::default())),
-        })?
+        loop {
+            match self.receiver.try_recv() {
+                Ok(Some(good)) => break self.received(good),
+                // The channel was closed, which is observed when the channel is empty.
+                Ok(None) => {}
+                Err(TryRecvError::Empty) => {
+                    if self.presence.is_ended() {
+                        throw!(self.failure(Fault::Defect(WithdrawnSupply::default())));
+                    }
+
+                    throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+                }
+                Err(TryRecvError::Disconnected) => {
+                    throw!(self.failure(Fault::Defect(WithdrawnSupply::default())))
+                }
+            }
+        }
     }
 }
 
@@ -45,13 +210,86 @@ impl<G> Display for StdReceiver<G> {
     }
 }
 
+impl<G> Inventory for StdReceiver<G> {
+    fn len(&self) -> usize {
+        usize::try_from(self.stock.load(Ordering::Relaxed)).unwrap_or(0)
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.presence.is_withdrawn()
+    }
+}
+
 /// The [`Producer`] for [`InfiniteChannel`] implemented by [`std`].
 #[derive(Debug)]
 pub struct StdSender<G> {
     /// The name of the sender.
-    name: String,
+    name: CloneName,
     /// The sender.
-    sender: Sender<G>,
+    sender: Sender<Option<G>>,
+    /// The number of goods stored in the channel.
+    stock: Arc<AtomicIsize>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+}
+
+impl<G> StdSender<G> {
+    /// Closes the channel to production.
+    ///
+    /// After `self` is closed, every producer of the channel, including `self` and its clones, throws [`WithdrawnDemand`], and the consumer of the channel consumes the remaining goods and then throws [`WithdrawnSupply`].
+    pub fn close(&self) {
+        self.presence.close();
+        // Wakes the consumer if it is blocked receiving.
+        let _ = self.sender.send(None);
+    }
+
+    /// Stores the goods of `goods` in the channel until one is recalled because the channel has no consumers or has been closed.
+    ///
+    /// Returns the goods that were not stored in their original order, which is empty if all goods were stored.
+    pub fn produce_batch<I: IntoIterator<Item = G>>(&self, goods: I) -> Vec<G> {
+        let mut goods = goods.into_iter();
+        let mut remainder = Vec::new();
+        let mut count = 0_usize;
+
+        if !self.presence.is_ended() {
+            for good in goods.by_ref() {
+                // A failed send returns the message that was sent, which holds the good.
+                if let Err(SendError(message)) = self.sender.send(Some(good)) {
+                    remainder.extend(message);
+                    break;
+                }
+
+                count = count.saturating_add(1);
+            }
+        }
+
+        remainder.extend(goods);
+        self.sent(count);
+        remainder
+    }
+
+    /// Records that `count` goods were added to the stock of the channel.
+    fn sent(&self, count: usize) {
+        let _ = self.stock.fetch_add(
+            isize::try_from(count).unwrap_or(isize::MAX),
+            Ordering::Relaxed,
+        );
+    }
+}
+
+impl<G> Clone for StdSender<G> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            sender: self.sender.clone(),
+            stock: Arc::clone(&self.stock),
+            presence: self.presence.clone(),
+        }
+    }
 }
 
 impl<G> Agent for StdSender<G> {
@@ -64,14 +302,35 @@ impl<G> Display for StdSender<G> {
     }
 }
 
+impl<G> Inventory for StdSender<G> {
+    fn len(&self) -> usize {
+        usize::try_from(self.stock.load(Ordering::Relaxed)).unwrap_or(0)
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.presence.is_withdrawn()
+    }
+}
+
 impl<G> Producer for StdSender<G> {
     type Flaws = WithdrawnDemand;
 
     #[throws(Recall<Self::Flaws, Self::Good>)]
     fn produce(&self, good: Self::Good) {
-        self.sender
-            .send(good)
-            .map_err(|error| self.recall(Fault::Defect(WithdrawnDemand::default()), error.0))?
+        if self.presence.is_ended() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
+        // A failed send returns the message that was sent, which holds the good.
+        if let Err(SendError(Some(unsent_good))) = self.sender.send(Some(good)) {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), unsent_good));
+        }
+
+        self.sent(1);
     }
 }
 
@@ -79,9 +338,174 @@ impl<G> Producer for StdSender<G> {
 #[derive(Debug)]
 pub struct StdSyncSender<G> {
     /// The name of the sender.
-    name: String,
+    name: CloneName,
     /// The sender.
-    sender: SyncSender<G>,
+    sender: SyncSender<Option<G>>,
+    /// The number of goods stored in the channel.
+    stock: Arc<AtomicIsize>,
+    /// The capacity of the channel.
+    capacity: usize,
+    /// If the consumer of the channel is blocked receiving or is preparing to block.
+    is_consumer_waiting: Arc<AtomicBool>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+}
+
+impl<G> StdSyncSender<G> {
+    /// Closes the channel to production.
+    ///
+    /// After `self` is closed, every producer of the channel, including `self` and its clones, throws [`WithdrawnDemand`], and the consumer of the channel consumes the remaining goods and then throws [`WithdrawnSupply`].
+    pub fn close(&self) {
+        self.presence.close();
+
+        // Wakes the consumer if it is blocked receiving. A channel without capacity only accepts the message once the consumer is blocked, so the message is retried while the consumer is preparing to block.
+        while let Err(TrySendError::Full(_)) = self.sender.try_send(None) {
+            if !self.is_consumer_waiting.load(Ordering::SeqCst) {
+                break;
+            }
+
+            thread::yield_now();
+        }
+    }
+
+    /// Stores the goods of `goods` in the channel until one is recalled because the channel is full, has no consumers or has been closed.
+    ///
+    /// Returns the goods that were not stored in their original order, which is empty if all goods were stored.
+    pub fn produce_batch<I: IntoIterator<Item = G>>(&self, goods: I) -> Vec<G> {
+        let mut goods = goods.into_iter();
+        let mut remainder = Vec::new();
+        let mut count = 0_usize;
+
+        if !self.presence.is_ended() {
+            for good in goods.by_ref() {
+                match self.sender.try_send(Some(good)) {
+                    Ok(()) => count = count.saturating_add(1),
+                    // A failed send returns the message that was sent, which holds the good.
+                    Err(TrySendError::Full(message) | TrySendError::Disconnected(message)) => {
+                        remainder.extend(message);
+                        break;
+                    }
+                }
+            }
+        }
+
+        remainder.extend(goods);
+        self.sent(count);
+        remainder
+    }
+
+    /// Stores `good` into the channel, blocking until stock is available.
+    ///
+    /// Because [`SyncSender`] is unable to interrupt a blocked send, a close of the channel by its consumer does not end a wait that has already begun; the wait ends once stock is available or the consumer is dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the channel has no consumers or has been closed, throws a [`WithdrawnDemand`] defect.
+    #[throws(Recall<ProductionFlaws<WithdrawnDemand>, G>)]
+    pub fn produce_wait(&self, good: G) {
+        if self.presence.is_ended() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
+        // A failed send returns the message that was sent, which holds the good.
+        if let Err(SendError(Some(unsent_good))) = self.sender.send(Some(good)) {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), unsent_good));
+        }
+
+        self.sent(1);
+    }
+
+    /// Stores `good` into the channel, blocking until stock is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws a [`FullStock`] insufficiency; if the channel has no consumers or has been closed, throws a [`WithdrawnDemand`] defect.
+    #[throws(Recall<ProductionFlaws<WithdrawnDemand>, G>)]
+    pub fn produce_timeout(&self, good: G, timeout: Duration) {
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            self.produce_deadline(good, deadline)?;
+        } else {
+            self.produce_wait(good)?;
+        }
+    }
+
+    /// Stores `good` into the channel, blocking until stock is available or `deadline` is reached.
+    ///
+    /// Because [`SyncSender`] does not provide a timed send, the thread blocks until the consumer receives a good or begins to receive, or the channel is closed, and then attempts to store `good` again.
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws a [`FullStock`] insufficiency; if the channel has no consumers or has been closed, throws a [`WithdrawnDemand`] defect.
+    #[throws(Recall<ProductionFlaws<WithdrawnDemand>, G>)]
+    pub fn produce_deadline(&self, good: G, deadline: Instant) {
+        match self
+            .presence
+            .signal()
+            .wait_until(Some(deadline), good, |good| self.attempt(good))
+        {
+            Ok(production) => production?,
+            Err(full_good) => {
+                throw!(self.recall(Fault::Insufficiency(FullStock::default()), full_good))
+            }
+        }
+    }
+
+    /// Attempts to store `good` into the channel.
+    ///
+    /// Returns the outcome of the production, or `good` if the channel is full.
+    fn attempt(&self, mut good: G) -> Attempt<G> {
+        loop {
+            if self.presence.is_ended() {
+                break Ok(Err(
+                    self.recall(Fault::Defect(WithdrawnDemand::default()), good)
+                ));
+            }
+
+            match self.sender.try_send(Some(good)) {
+                Err(TrySendError::Full(Some(full_good))) => {
+                    // A consumer that is preparing to block is about to accept a good, even from a channel without capacity.
+                    if !self.is_consumer_waiting.load(Ordering::SeqCst) {
+                        break Err(full_good);
+                    }
+
+                    good = full_good;
+                    thread::yield_now();
+                }
+                Err(TrySendError::Disconnected(Some(disconnected_good))) => {
+                    break Ok(Err(self.recall(
+                        Fault::Defect(WithdrawnDemand::default()),
+                        disconnected_good,
+                    )));
+                }
+                // A failed send returns the message that was sent, which holds the good.
+                Ok(()) | Err(_) => {
+                    self.sent(1);
+                    break Ok(Ok(()));
+                }
+            }
+        }
+    }
+
+    /// Records that `count` goods were added to the stock of the channel.
+    fn sent(&self, count: usize) {
+        let _ = self.stock.fetch_add(
+            isize::try_from(count).unwrap_or(isize::MAX),
+            Ordering::Relaxed,
+        );
+    }
+}
+
+impl<G> Clone for StdSyncSender<G> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            sender: self.sender.clone(),
+            stock: Arc::clone(&self.stock),
+            capacity: self.capacity,
+            is_consumer_waiting: Arc::clone(&self.is_consumer_waiting),
+            presence: self.presence.clone(),
+        }
+    }
 }
 
 impl<G> Agent for StdSyncSender<G> {
@@ -94,19 +518,39 @@ impl<G> Display for StdSyncSender<G> {
     }
 }
 
+impl<G> Inventory for StdSyncSender<G> {
+    fn len(&self) -> usize {
+        usize::try_from(self.stock.load(Ordering::Relaxed)).unwrap_or(0)
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(self.capacity)
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.presence.is_withdrawn()
+    }
+}
+
 impl<G> Producer for StdSyncSender<G> {
     type Flaws = ProductionFlaws<WithdrawnDemand>;
 
     #[throws(Recall<Self::Flaws, Self::Good>)]
     fn produce(&self, good: Self::Good) {
-        self.sender.try_send(good).map_err(|error| match error {
-            TrySendError::Full(good) => {
-                self.recall(Fault::Insufficiency(FullStock::default()), good)
+        if self.presence.is_ended() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
+        match self.sender.try_send(Some(good)) {
+            Err(TrySendError::Full(Some(full_good))) => {
+                throw!(self.recall(Fault::Insufficiency(FullStock::default()), full_good))
             }
-            TrySendError::Disconnected(good) => {
-                self.recall(Fault::Defect(WithdrawnDemand::default()), good)
+            Err(TrySendError::Disconnected(Some(disconnected_good))) => {
+                throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), disconnected_good))
             }
-        })?
+            // A failed send returns the message that was sent, which holds the good.
+            Ok(()) | Err(_) => self.sent(1),
+        }
     }
 }
 
@@ -126,13 +570,24 @@ impl<G> InfiniteChannel<G> for StdInfiniteChannel<G> {
         S: AsRef<str> + ?Sized,
     {
         let (sender, receiver) = mpsc::channel();
-        let name = String::from(name_str.as_ref());
+        let name = name_str.as_ref();
+        let stock = Arc::new(AtomicIsize::new(0));
+        let (sender_presence, receiver_presence) = Presence::pair();
         (
             StdSender {
-                name: name.clone(),
+                name: CloneName::new(name),
                 sender,
+                stock: Arc::clone(&stock),
+                presence: sender_presence,
+            },
+            StdReceiver {
+                name: String::from(name),
+                receiver,
+                stock,
+                capacity: None,
+                is_waiting: Arc::new(AtomicBool::new(false)),
+                presence: receiver_presence,
             },
-            StdReceiver { name, receiver },
         )
     }
 }
@@ -153,13 +608,46 @@ impl<G> FiniteChannel<G> for StdFiniteChannel<G> {
         S: AsRef<str> + ?Sized,
     {
         let (sender, receiver) = mpsc::sync_channel(size);
-        let name = String::from(name_str.as_ref());
+        let name = name_str.as_ref();
+        let stock = Arc::new(AtomicIsize::new(0));
+        let is_waiting = Arc::new(AtomicBool::new(false));
+        let (sender_presence, receiver_presence) = Presence::pair();
         (
             StdSyncSender {
-                name: name.clone(),
+                name: CloneName::new(name),
                 sender,
+                stock: Arc::clone(&stock),
+                capacity: size,
+                is_consumer_waiting: Arc::clone(&is_waiting),
+                presence: sender_presence,
+            },
+            StdReceiver {
+                name: String::from(name),
+                receiver,
+                stock,
+                capacity: Some(size),
+                is_waiting,
+                presence: receiver_presence,
             },
-            StdReceiver { name, receiver },
         )
     }
 }
+
+/// A channel implemented by [`std`] that has no capacity.
+///
+/// A good is only produced when the consumer is waiting to consume it, so a producer that does not wait, such as [`Producer::produce()`], throws [`FullStock`] unless the consumer is blocked in a call such as [`StdReceiver::consume_wait()`]. This is the same channel created by [`StdFiniteChannel`] with a size of 0.
+#[derive(Debug)]
+pub struct StdRendezvousChannel<G> {
+    /// The type of the good exchanged by the channel.
+    good: PhantomData<G>,
+}
+
+impl<G> StdRendezvousChannel<G> {
+    /// Creates the [`Producer`] and [`Consumer`] connected to a channel with no capacity.
+    pub fn establish<S>(name_str: &S) -> (StdSyncSender<G>, StdReceiver<G>)
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        StdFiniteChannel::establish(name_str, 0)
+    }
+}
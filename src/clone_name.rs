@@ -0,0 +1,51 @@
+//! Implements the name of an agent that gives each of its clones a distinct name.
+use {
+    alloc::sync::Arc,
+    core::{
+        fmt::{self, Display, Formatter},
+        sync::atomic::{AtomicUsize, Ordering},
+    },
+};
+
+/// The name of an agent that identifies each clone of the agent.
+#[derive(Debug)]
+pub(crate) struct CloneName {
+    /// The name given to the original agent.
+    name: Arc<str>,
+    /// The number of clones that have been made from the original agent.
+    clone_count: Arc<AtomicUsize>,
+    /// Identifies the clone, where 0 is the original agent.
+    index: usize,
+}
+
+impl CloneName {
+    /// Creates the [`CloneName`] of an original agent named `name`.
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: Arc::from(name),
+            clone_count: Arc::new(AtomicUsize::new(0)),
+            index: 0,
+        }
+    }
+}
+
+impl Clone for CloneName {
+    fn clone(&self) -> Self {
+        Self {
+            name: Arc::clone(&self.name),
+            clone_count: Arc::clone(&self.clone_count),
+            index: self.clone_count.fetch_add(1, Ordering::Relaxed) + 1,
+        }
+    }
+}
+
+impl Display for CloneName {
+    /// Writes "{name}" for the original agent and "{name} (clone {index})" for each clone.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.index == 0 {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{} (clone {})", self.name, self.index)
+        }
+    }
+}
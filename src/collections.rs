@@ -1,47 +1,60 @@
 //! Implements [`Producer`] and [`Consumer`] for sequences of agents.
 use {
-    crate::convert::{Adapter, SpecificationDefect, SpecificationFlaws, Specifier},
+    crate::{
+        convert::{Adapter, SpecificationDefect, SpecificationFlaws, Specifier},
+        inventory::Inventory,
+        sync::lock,
+    },
     alloc::{boxed::Box, string::String, vec::Vec},
     core::{
-        cmp::Eq,
+        cell::{Cell, RefCell},
+        cmp::{self, Eq, Ordering},
         convert::TryFrom,
         fmt::{self, Debug, Display, Formatter},
         marker::PhantomData,
+        num::NonZeroUsize,
     },
     fehler::{throw, throws},
     market::{
-        Agent, Blame, Consumer, ConsumptionFlaws, EmptyStock, Failure, Fault, Flaws, Producer,
-        Recall,
+        Agent, Blame, Consumer, ConsumptionFlaws, EmptyStock, Failure, Fault, Flaws, FullStock,
+        Producer, ProductionFlaws, Recall, TryBlame,
+    },
+    std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        hash::{Hash, Hasher},
+        sync::Mutex,
+        time::{Duration, Instant},
     },
-    std::{collections::HashMap, hash::Hash},
 };
 
-/// The [`Flaws`] of a [`Distributor`].
+/// The [`Flaws`] of a [`Distributor`] that describes missing keys with `M`.
 #[derive(Debug)]
-pub struct DistributionFlaws<F> {
+pub struct DistributionFlaws<M, F> {
+    /// The description of a missing key.
+    _missing: PhantomData<M>,
     /// The type of the [`Flaws`].
     _flaws: PhantomData<F>,
 }
 
-impl<F> Flaws for DistributionFlaws<F>
+impl<M, F> Flaws for DistributionFlaws<M, F>
 where
     F: Flaws,
 {
     type Insufficiency = F::Insufficiency;
-    type Defect = DistributionDefect<F::Defect>;
+    type Defect = DistributionDefect<M, F::Defect>;
 }
 
 /// The error thrown by a [`Distributor`].
 #[derive(Debug)]
 #[non_exhaustive]
-pub enum DistributionDefect<F> {
+pub enum DistributionDefect<M, F> {
     /// The [`Specifier`] failed.
     Specification(SpecificationDefect<F>),
-    /// The given key was not found in the [`Distributor`].
-    MissingKey,
+    /// No [`Producer`] is mapped to the key of the good, which is described as specified by the [`Route`] of the [`Distributor`].
+    MissingKey(M),
 }
 
-impl<F> From<SpecificationDefect<F>> for DistributionDefect<F> {
+impl<M, F> From<SpecificationDefect<F>> for DistributionDefect<M, F> {
     fn from(defect: SpecificationDefect<F>) -> Self {
         Self::Specification(defect)
     }
@@ -53,28 +66,151 @@ pub trait Keyed<K> {
     fn key(&self) -> &K;
 }
 
-/// A [`Producer`] that produces goods of type `G` to multiple [`Producer`]s.
-pub struct Distributor<K, G, F> {
+/// Characterizes how a [`Distributor`] determines the key of type `K` of each good of type `G`.
+pub trait Route<G, K> {
+    /// Describes a key that is not mapped to a [`Producer`].
+    type Missing;
+
+    /// Returns the item of `items` that is mapped to the key of `good`.
+    ///
+    /// # Errors
+    ///
+    /// If no item is mapped to the key of `good`, throws the description of the key.
+    #[throws(Self::Missing)]
+    fn select<'a, T>(&self, good: &G, items: &'a HashMap<K, T>) -> &'a T;
+}
+
+/// The [`Route`] of a [`Distributor`] created by [`Distributor::new()`], which determines the key of each good by [`Keyed::key()`].
+///
+/// A missing key is described by `()` so that keys are not required to be cloned; the key can be retrieved from the recalled good.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeyedRoute;
+
+impl<G, K> Route<G, K> for KeyedRoute
+where
+    G: Keyed<K>,
+    K: Eq + Hash,
+{
+    type Missing = ();
+
+    #[throws(Self::Missing)]
+    fn select<'a, T>(&self, good: &G, items: &'a HashMap<K, T>) -> &'a T {
+        items.get(good.key()).ok_or(())?
+    }
+}
+
+/// The [`Route`] of a [`Distributor`] created by [`Distributor::with_key()`], which computes the key of each good with a closure.
+///
+/// A missing key is described by the computed key.
+pub struct ComputedRoute<G, K> {
+    /// Computes the key of a good.
+    key: Box<dyn Fn(&G) -> K>,
+}
+
+impl<G, K> Debug for ComputedRoute<G, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ComputedRoute {{ .. }}")
+    }
+}
+
+impl<G, K> Route<G, K> for ComputedRoute<G, K>
+where
+    K: Eq + Hash,
+{
+    type Missing = K;
+
+    #[throws(Self::Missing)]
+    fn select<'a, T>(&self, good: &G, items: &'a HashMap<K, T>) -> &'a T {
+        let key = (self.key)(good);
+
+        items.get(&key).ok_or(key)?
+    }
+}
+
+/// A defect that cannot occur, used to separate the insufficiencies from the defects of a [`Recall`].
+#[derive(Debug)]
+enum Rejected {}
+
+impl<D> TryFrom<SpecificationDefect<D>> for Rejected {
+    type Error = SpecificationDefect<D>;
+
+    fn try_from(defect: SpecificationDefect<D>) -> Result<Self, Self::Error> {
+        Err(defect)
+    }
+}
+
+impl<M, D> From<Rejected> for DistributionDefect<M, D> {
+    fn from(rejected: Rejected) -> Self {
+        match rejected {}
+    }
+}
+
+/// The [`Flaws`] of a [`Recall`] that is known to be caused by an insufficiency of type `I`.
+struct InsufficiencyFlaws<I> {
+    /// The type of the insufficiency.
+    _insufficiency: PhantomData<I>,
+}
+
+impl<I> Flaws for InsufficiencyFlaws<I> {
+    type Insufficiency = I;
+    type Defect = Rejected;
+}
+
+/// A [`Producer`] that produces goods of type `G` to multiple [`Producer`]s, selected by the key that the [`Route`] `R` determines for each good.
+pub struct Distributor<K, G, F, R = KeyedRoute> {
     // The Good of each Producer must be G so that all Producers have the same interface and can be elements of a single HashMap.
     /// The [`Producer`]s.
     producers: HashMap<K, Box<dyn Producer<Good = G, Flaws = SpecificationFlaws<F>>>>,
+    /// Determines the key of each good.
+    route: R,
+    /// The [`Producer`] of goods with keys that are not mapped to a [`Producer`].
+    fallback: Option<Box<dyn Producer<Good = G, Flaws = SpecificationFlaws<F>>>>,
+    /// The [`Producer`] of goods that could not be distributed due to a defect.
+    dead_letter: Option<Box<dyn Producer<Good = G, Flaws = SpecificationFlaws<F>>>>,
     /// The name of the distributor.
     name: String,
 }
 
-impl<K, G: 'static, F: Flaws + 'static> Distributor<K, G, F> {
-    /// Creates a new, empty [`Distributor`].
+impl<K, G, F> Distributor<K, G, F> {
+    /// Creates a new, empty [`Distributor`] that produces each good to the [`Producer`] mapped to [`Keyed::key()`].
     #[must_use]
     pub fn new<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self::with_route(name_str, KeyedRoute)
+    }
+}
+
+impl<K, G, F> Distributor<K, G, F, ComputedRoute<G, K>> {
+    /// Creates a new, empty [`Distributor`] that produces each good to the [`Producer`] mapped to the key computed by `key`.
+    #[must_use]
+    pub fn with_key<S, R>(name_str: &S, key: R) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        R: Fn(&G) -> K + 'static,
+    {
+        Self::with_route(name_str, ComputedRoute { key: Box::new(key) })
+    }
+}
+
+impl<K, G, F, R> Distributor<K, G, F, R> {
+    /// Creates a new, empty [`Distributor`] that determines the key of each good with `route`.
+    fn with_route<S>(name_str: &S, route: R) -> Self
     where
         S: AsRef<str> + ?Sized,
     {
         Self {
             producers: HashMap::new(),
+            route,
+            fallback: None,
+            dead_letter: None,
             name: String::from(name_str.as_ref()),
         }
     }
+}
 
+impl<K, G: 'static, F: Flaws + 'static, R> Distributor<K, G, F, R> {
     /// Inserts a mapping of `producer` to `key` into `self`.
     pub fn insert<P: Producer + 'static>(
         &mut self,
@@ -91,113 +227,1568 @@ impl<K, G: 'static, F: Flaws + 'static> Distributor<K, G, F> {
         self.producers
             .insert(key, Box::new(Specifier::new(producer)))
     }
+
+    /// Inserts a mapping of `producer`, which already produces goods of type `G`, to `key` into `self`.
+    ///
+    /// This is used to insert a [`Producer`] that specifies goods with a closure, such as a [`MapSpecifier`](crate::convert::MapSpecifier) or [`FilterMapSpecifier`](crate::convert::FilterMapSpecifier).
+    pub fn insert_specified<P>(
+        &mut self,
+        key: K,
+        producer: P,
+    ) -> Option<Box<dyn Producer<Good = G, Flaws = SpecificationFlaws<F>>>>
+    where
+        K: Eq + Hash,
+        P: Producer<Good = G, Flaws = SpecificationFlaws<F>> + 'static,
+    {
+        self.producers.insert(key, Box::new(producer))
+    }
+
+    /// Removes the mapping of `key` from `self`, returning the [`Producer`] that was mapped to `key`.
+    pub fn remove(
+        &mut self,
+        key: &K,
+    ) -> Option<Box<dyn Producer<Good = G, Flaws = SpecificationFlaws<F>>>>
+    where
+        K: Eq + Hash,
+    {
+        self.producers.remove(key)
+    }
+
+    /// Returns if a [`Producer`] is mapped to `key`.
+    pub fn contains_key(&self, key: &K) -> bool
+    where
+        K: Eq + Hash,
+    {
+        self.producers.contains_key(key)
+    }
+
+    /// Returns an iterator over the keys that are mapped to a [`Producer`], in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.producers.keys()
+    }
+
+    /// Returns the number of [`Producer`]s mapped to a key.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.producers.len()
+    }
+
+    /// Returns if no [`Producer`] is mapped to a key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.producers.is_empty()
+    }
+
+    /// Sets the [`Producer`] of goods with keys that are not mapped to a [`Producer`].
+    #[must_use]
+    pub fn with_fallback<P: Producer + 'static>(mut self, producer: P) -> Self
+    where
+        P::Good: TryFrom<G, Error = G>,
+        G: From<P::Good>,
+        SpecificationDefect<F::Defect>: From<<P::Flaws as Flaws>::Defect>,
+        F::Insufficiency: From<<P::Flaws as Flaws>::Insufficiency>,
+    {
+        self.fallback = Some(Box::new(Specifier::new(producer)));
+        self
+    }
+
+    /// Sets the dead-letter [`Producer`], which receives each good that could not be distributed due to a defect.
+    ///
+    /// This includes goods with keys that are not mapped to a [`Producer`] when there is no fallback, goods that could not be specified and goods recalled by a [`Producer`] due to a defect.
+    #[must_use]
+    pub fn with_dead_letter<P: Producer + 'static>(mut self, producer: P) -> Self
+    where
+        P::Good: TryFrom<G, Error = G>,
+        G: From<P::Good>,
+        SpecificationDefect<F::Defect>: From<<P::Flaws as Flaws>::Defect>,
+        F::Insufficiency: From<<P::Flaws as Flaws>::Insufficiency>,
+    {
+        self.dead_letter = Some(Box::new(Specifier::new(producer)));
+        self
+    }
+}
+
+impl<G, F> Distributor<usize, G, F, ComputedRoute<G, usize>> {
+    /// Creates a new, empty [`Distributor`] that produces each good to the partition, from `0` to `partitions - 1`, selected by the hash of the value computed by `key`.
+    #[must_use]
+    pub fn hash_partitioned<S, H, R>(name_str: &S, partitions: NonZeroUsize, key: R) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        H: Hash,
+        R: Fn(&G) -> H + 'static,
+    {
+        // Only a usize wider than u64 does not fit, in which case the goods are spread over the first u64::MAX partitions.
+        let partition_count = u64::try_from(partitions.get()).unwrap_or(u64::MAX);
+
+        Self::with_key(name_str, move |good| {
+            let mut hasher = DefaultHasher::new();
+            key(good).hash(&mut hasher);
+            // The remainder is less than partitions, so it always fits in a usize.
+            usize::try_from(hasher.finish() % partition_count).unwrap_or(0)
+        })
+    }
+
+    /// Creates a new, empty [`Distributor`] that produces each good to the partition selected by comparing the value computed by `key` with `bounds`.
+    ///
+    /// `bounds` shall be sorted in ascending order. Partition `i` receives the goods with values that are at least `bounds[i - 1]` and less than `bounds[i]`, so there are `bounds.len() + 1` partitions.
+    #[must_use]
+    pub fn range_partitioned<S, B, R>(name_str: &S, bounds: Vec<B>, key: R) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        B: Ord + 'static,
+        R: Fn(&G) -> B + 'static,
+    {
+        Self::with_key(name_str, move |good| {
+            let value = key(good);
+            bounds.partition_point(|bound| *bound <= value)
+        })
+    }
 }
 
-impl<K, G, F> Agent for Distributor<K, G, F> {
+impl<K, G, F, R> Agent for Distributor<K, G, F, R> {
     type Good = G;
 }
 
-impl<K, G, F> Debug for Distributor<K, G, F> {
+impl<K, G, F, R> Debug for Distributor<K, G, F, R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "Distributor {{ .. }}")
     }
 }
 
-impl<K, G, F> Display for Distributor<K, G, F> {
+impl<K, G, F, R> Display for Distributor<K, G, F, R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.name)
     }
 }
 
-impl<K, G, F> Producer for Distributor<K, G, F>
+impl<K, G, F, R> Producer for Distributor<K, G, F, R>
 where
     F: Flaws,
-    G: Keyed<K>,
     K: Eq + Hash,
+    R: Route<G, K>,
 {
-    type Flaws = DistributionFlaws<F>;
+    type Flaws = DistributionFlaws<R::Missing, F>;
 
     #[throws(Recall<Self::Flaws, Self::Good>)]
     fn produce(&self, good: Self::Good) {
-        if let Some(producer) = self.producers.get(good.key()) {
-            producer.produce(good).map_err(|recall| recall.blame())?;
+        let selection = self.route.select(&good, &self.producers);
+
+        match selection.or_else(|missing_key| self.fallback.as_ref().ok_or(missing_key)) {
+            Ok(producer) => {
+                if let Err(recall) = producer.produce(good) {
+                    self.reject(recall)?;
+                }
+            }
+            Err(missing_key) => {
+                if let Some(dead_letter) = self.dead_letter.as_ref() {
+                    dead_letter.produce(good).map_err(|recall| recall.blame())?;
+                } else {
+                    throw!(self.recall(
+                        Fault::Defect(DistributionDefect::MissingKey(missing_key)),
+                        good
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl<K, G, F, R> Distributor<K, G, F, R>
+where
+    F: Flaws,
+{
+    /// Produces the good of `recall` to the dead-letter [`Producer`] if `recall` was caused by a defect; otherwise throws `recall`.
+    #[throws(Recall<DistributionFlaws<M, F>, G>)]
+    fn reject<M>(&self, recall: Recall<SpecificationFlaws<F>, G>) {
+        if let Some(dead_letter) = self.dead_letter.as_ref() {
+            match TryBlame::<Recall<InsufficiencyFlaws<F::Insufficiency>, G>>::try_blame(recall) {
+                Ok(insufficiency) => throw!(insufficiency.blame()),
+                Err(error) => dead_letter
+                    .produce(error.into_good())
+                    .map_err(|recall| recall.blame())?,
+            }
         } else {
-            throw!(self.recall(Fault::Defect(DistributionDefect::MissingKey), good));
+            throw!(recall.blame());
         }
     }
 }
 
-/// A [`Consumer`] that consumes goods of type `G` from multiple [`Consumer`]s.
-pub struct Collector<G, F> {
-    /// The [`Consumer`]s.
-    consumers: Vec<Box<dyn Consumer<Good = G, Flaws = F>>>,
-    /// The name of the collector.
+/// A [`Producer`] of a [`BroadcastDistributor`].
+trait Sink: Producer {
+    /// Returns if `self` is able to store another good, assuming so if unknown.
+    fn has_room(&self) -> bool;
+
+    /// Converts `self` into a [`Producer`].
+    fn into_producer(self: Box<Self>) -> Box<dyn Producer<Good = Self::Good, Flaws = Self::Flaws>>;
+}
+
+/// A [`Producer`] that converts the [`Flaws`] of the producer `P` into `F`.
+struct Blamer<P, F> {
+    /// The actual producer.
+    producer: P,
+    /// Returns if `producer` is able to store another good.
+    gauge: fn(&P) -> bool,
+    /// The type of self's [`Flaws`].
+    _flaws: PhantomData<F>,
+}
+
+impl<P: Agent, F> Agent for Blamer<P, F> {
+    type Good = P::Good;
+}
+
+impl<P: Display, F> Display for Blamer<P, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.producer)
+    }
+}
+
+impl<P, F> Producer for Blamer<P, F>
+where
+    P: Producer,
+    F: Flaws,
+    F::Insufficiency: From<<P::Flaws as Flaws>::Insufficiency>,
+    F::Defect: From<<P::Flaws as Flaws>::Defect>,
+{
+    type Flaws = F;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        self.producer
+            .produce(good)
+            .map_err(|recall| recall.blame())?;
+    }
+}
+
+impl<P, F> Sink for Blamer<P, F>
+where
+    P: Producer + 'static,
+    F: Flaws + 'static,
+    F::Insufficiency: From<<P::Flaws as Flaws>::Insufficiency>,
+    F::Defect: From<<P::Flaws as Flaws>::Defect>,
+{
+    fn has_room(&self) -> bool {
+        (self.gauge)(&self.producer)
+    }
+
+    fn into_producer(self: Box<Self>) -> Box<dyn Producer<Good = Self::Good, Flaws = Self::Flaws>> {
+        self
+    }
+}
+
+/// The [`Flaws`] of a [`BroadcastDistributor`] with keys of type `K`.
+#[derive(Debug)]
+pub struct FanOutFlaws<K, F> {
+    /// The type of the keys.
+    _key: PhantomData<K>,
+    /// The type of the [`Flaws`].
+    _flaws: PhantomData<F>,
+}
+
+impl<K, F> Flaws for FanOutFlaws<K, F>
+where
+    F: Flaws,
+{
+    type Insufficiency = F::Insufficiency;
+    type Defect = FanOutDefect<K, F::Defect>;
+}
+
+/// The defect thrown by a [`BroadcastDistributor`].
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum FanOutDefect<K, D> {
+    /// A [`Producer`] threw the defect.
+    Production(D),
+    /// The [`Producer`]s with the given keys recalled the good.
+    Recalled(Vec<K>),
+    /// Goods retained for the [`Producer`]s with the given keys have not been produced, so the good was not produced to any [`Producer`].
+    Retaining(Vec<K>),
+    /// The [`Producer`]s with the given keys are unable to store the good, so the good was not produced to any [`Producer`].
+    Full(Vec<K>),
+}
+
+impl<K: Debug, D: Display> Display for FanOutDefect<K, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Production(ref defect) => write!(f, "{}", defect),
+            Self::Recalled(ref keys) => write!(f, "recalled by producers {:?}", keys),
+            Self::Retaining(ref keys) => write!(f, "retaining goods for producers {:?}", keys),
+            Self::Full(ref keys) => write!(f, "producers {:?} are full", keys),
+        }
+    }
+}
+
+impl<K, D> From<D> for FanOutDefect<K, D> {
+    fn from(defect: D) -> Self {
+        Self::Production(defect)
+    }
+}
+
+/// Specifies how a [`BroadcastDistributor`] handles a good that is recalled by some of its [`Producer`]s.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FanOutPolicy {
+    /// Produces to each [`Producer`] in order and throws the [`Recall`] of the first [`Producer`] that recalls the good; later [`Producer`]s are not attempted.
+    FailFast,
+    /// Produces to every [`Producer`] and throws [`FanOutDefect::Recalled`] with the keys of those that recalled the good.
+    BestEffort,
+    /// Produces to every [`Producer`] only if every [`Producer`] is able to store the good, retaining the good for each [`Producer`] that still recalls it.
+    ///
+    /// Before producing, each [`Producer`] inserted by [`BroadcastDistributor::insert_gauged()`] is checked for room; if any is full or withdrawn, [`FanOutDefect::Full`] is thrown and the good is not produced to any [`Producer`]. A [`Producer`] inserted by [`BroadcastDistributor::insert()`] is assumed to have room, so if it recalls the good anyway, the good is retained for it and produced again before the next good. While any retained good is recalled, a new good is not produced to any [`Producer`] and [`FanOutDefect::Retaining`] is thrown.
+    AllOrNothing,
+}
+
+/// A [`Producer`] of a [`BroadcastDistributor`].
+struct Outlet<K, G, F> {
+    /// The key of the [`Producer`].
+    key: K,
+    /// Identifies the [`Producer`] for the lifetime of the [`BroadcastDistributor`], even if another [`Producer`] replaces it.
+    id: u64,
+    /// The [`Producer`].
+    producer: Box<dyn Sink<Good = G, Flaws = F>>,
+}
+
+/// A [`Producer`] that produces a clone of each good of type `G` to every one of its [`Producer`]s.
+pub struct BroadcastDistributor<K, G, F> {
+    /// The [`Producer`]s, in the order they were inserted.
+    outlets: Vec<Outlet<K, G, F>>,
+    /// The id of the next inserted [`Producer`].
+    next_id: u64,
+    /// How a good recalled by some [`Producer`]s is handled.
+    policy: FanOutPolicy,
+    /// The goods retained by [`FanOutPolicy::AllOrNothing`], along with the id of the [`Producer`] each good is retained for.
+    retained: Mutex<Vec<(u64, G)>>,
+    /// The name of the distributor.
     name: String,
 }
 
-impl<G, F: Flaws> Collector<G, F> {
-    /// Creates a new, empty [`Collector`].
+impl<K, G: 'static, F: Flaws + 'static> BroadcastDistributor<K, G, F> {
+    /// Creates a new, empty [`BroadcastDistributor`] that handles recalls according to `policy`.
     #[must_use]
-    pub fn new(name: String) -> Self {
+    pub fn new<S>(name_str: &S, policy: FanOutPolicy) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
         Self {
-            consumers: Vec::new(),
-            name,
+            outlets: Vec::new(),
+            next_id: 0,
+            policy,
+            retained: Mutex::new(Vec::new()),
+            name: String::from(name_str.as_ref()),
         }
     }
 
-    /// Adds `consumer` to the end of the [`Consumer`]s held by `self`.
-    pub fn push<C: Consumer + 'static>(&mut self, consumer: C)
+    /// Inserts `producer` with `key` into `self`, returning the [`Producer`] it replaced.
+    ///
+    /// `producer` is assumed to always have room for a good. Goods retained for a replaced [`Producer`] are discarded.
+    pub fn insert<P: Producer<Good = G> + 'static>(
+        &mut self,
+        key: K,
+        producer: P,
+    ) -> Option<Box<dyn Producer<Good = G, Flaws = F>>>
     where
-        F: 'static,
-        G: From<C::Good> + 'static,
-        F::Insufficiency: From<<C::Flaws as Flaws>::Insufficiency>,
-        F::Defect: From<<C::Flaws as Flaws>::Defect>,
+        K: Eq,
+        F::Insufficiency: From<<P::Flaws as Flaws>::Insufficiency>,
+        F::Defect: From<<P::Flaws as Flaws>::Defect>,
     {
-        self.consumers.push(Box::new(Adapter::new(consumer)));
+        self.insert_sink(
+            key,
+            Box::new(Blamer {
+                producer,
+                gauge: |_| true,
+                _flaws: PhantomData,
+            }),
+        )
     }
-}
 
-impl<G, F> Agent for Collector<G, F> {
-    type Good = G;
-}
+    /// Inserts `producer` with `key` into `self`, returning the [`Producer`] it replaced.
+    ///
+    /// Whether `producer` has room for a good is determined via [`Inventory`]. Goods retained for a replaced [`Producer`] are discarded.
+    pub fn insert_gauged<P: Producer<Good = G> + Inventory + 'static>(
+        &mut self,
+        key: K,
+        producer: P,
+    ) -> Option<Box<dyn Producer<Good = G, Flaws = F>>>
+    where
+        K: Eq,
+        F::Insufficiency: From<<P::Flaws as Flaws>::Insufficiency>,
+        F::Defect: From<<P::Flaws as Flaws>::Defect>,
+    {
+        self.insert_sink(
+            key,
+            Box::new(Blamer {
+                producer,
+                gauge: |producer| {
+                    !producer.is_withdrawn()
+                        && match producer.capacity() {
+                            Some(capacity) => producer.len() < capacity,
+                            None => true,
+                        }
+                },
+                _flaws: PhantomData,
+            }),
+        )
+    }
 
-impl<G, F: Flaws> Consumer for Collector<G, F>
-where
-    F::Defect: Flaws,
-    EmptyStock: From<F::Insufficiency>,
-    <F::Defect as Flaws>::Defect: From<F::Defect>,
-{
-    type Flaws = ConsumptionFlaws<F::Defect>;
+    /// Inserts `sink` with `key` into `self`, returning the [`Producer`] it replaced.
+    fn insert_sink(
+        &mut self,
+        key: K,
+        sink: Box<dyn Sink<Good = G, Flaws = F>>,
+    ) -> Option<Box<dyn Producer<Good = G, Flaws = F>>>
+    where
+        K: Eq,
+    {
+        let id = self.next_id;
 
-    #[throws(Failure<Self::Flaws>)]
-    fn consume(&self) -> Self::Good {
-        let mut result: Result<Self::Good, Failure<Self::Flaws>> =
-            Err(self.failure(Fault::Insufficiency(EmptyStock::default())));
+        self.next_id = id.wrapping_add(1);
 
-        for consumer in &self.consumers {
-            result = consumer.consume().map_err(|failure| failure.blame());
+        if let Some(existing) = self.outlets.iter_mut().find(|outlet| outlet.key == key) {
+            let replaced_id = existing.id;
 
-            if let Err(ref failure) = result {
-                if failure.is_defect() {
-                    break;
-                }
-            } else {
-                break;
-            }
+            lock(&self.retained).retain(|entry| entry.0 != replaced_id);
+            existing.id = id;
+            Some(core::mem::replace(&mut existing.producer, sink).into_producer())
+        } else {
+            self.outlets.push(Outlet {
+                key,
+                id,
+                producer: sink,
+            });
+            None
         }
+    }
 
-        result?
+    /// Discards all goods retained by [`FanOutPolicy::AllOrNothing`].
+    pub fn clear_retained(&self) {
+        lock(&self.retained).clear();
     }
 }
 
-impl<G, F> Debug for Collector<G, F> {
+impl<K, G, F> Agent for BroadcastDistributor<K, G, F> {
+    type Good = G;
+}
+
+impl<K, G, F> Debug for BroadcastDistributor<K, G, F> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Collector {{ .. }}")
+        write!(
+            f,
+            "BroadcastDistributor {{ policy: {:?}, .. }}",
+            self.policy
+        )
     }
 }
 
-impl<G, F> Display for Collector<G, F> {
+impl<K, G, F> Display for BroadcastDistributor<K, G, F> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Collector for {}", self.name)
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<K, G, F> Producer for BroadcastDistributor<K, G, F>
+where
+    K: Clone,
+    G: Clone,
+    F: Flaws,
+{
+    type Flaws = FanOutFlaws<K, F>;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        match self.policy {
+            FanOutPolicy::FailFast => {
+                for outlet in &self.outlets {
+                    outlet
+                        .producer
+                        .produce(good.clone())
+                        .map_err(|recall| recall.blame())?;
+                }
+            }
+            FanOutPolicy::BestEffort => {
+                let recalled: Vec<K> = self
+                    .outlets
+                    .iter()
+                    .filter(|outlet| outlet.producer.produce(good.clone()).is_err())
+                    .map(|outlet| outlet.key.clone())
+                    .collect();
+
+                if !recalled.is_empty() {
+                    throw!(self.recall(Fault::Defect(FanOutDefect::Recalled(recalled)), good));
+                }
+            }
+            FanOutPolicy::AllOrNothing => {
+                let mut retained = lock(&self.retained);
+
+                retained.retain(|entry| {
+                    self.outlets.iter().any(|outlet| {
+                        outlet.id == entry.0 && outlet.producer.produce(entry.1.clone()).is_err()
+                    })
+                });
+
+                if !retained.is_empty() {
+                    let keys = self
+                        .outlets
+                        .iter()
+                        .filter(|outlet| retained.iter().any(|entry| entry.0 == outlet.id))
+                        .map(|outlet| outlet.key.clone())
+                        .collect();
+
+                    throw!(self.recall(Fault::Defect(FanOutDefect::Retaining(keys)), good));
+                }
+
+                let full: Vec<K> = self
+                    .outlets
+                    .iter()
+                    .filter(|outlet| !outlet.producer.has_room())
+                    .map(|outlet| outlet.key.clone())
+                    .collect();
+
+                if !full.is_empty() {
+                    throw!(self.recall(Fault::Defect(FanOutDefect::Full(full)), good));
+                }
+
+                for outlet in &self.outlets {
+                    if outlet.producer.produce(good.clone()).is_err() {
+                        retained.push((outlet.id, good.clone()));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An insufficiency that cannot occur, used to separate the defects from the insufficiencies of a [`Recall`].
+#[derive(Debug)]
+enum Exhausted {}
+
+impl TryFrom<FullStock> for Exhausted {
+    type Error = FullStock;
+
+    fn try_from(insufficiency: FullStock) -> Result<Self, Self::Error> {
+        Err(insufficiency)
+    }
+}
+
+impl From<Exhausted> for FullStock {
+    fn from(exhausted: Exhausted) -> Self {
+        match exhausted {}
+    }
+}
+
+/// The [`Flaws`] of a [`Recall`] that is known to be caused by a defect.
+struct DefectFlaws<D> {
+    /// The type of the defect.
+    _defect: PhantomData<D>,
+}
+
+impl<D> Flaws for DefectFlaws<D> {
+    type Insufficiency = Exhausted;
+    type Defect = D;
+}
+
+/// A [`Producer`] of a [`LoadBalancer`].
+trait Member: Producer {
+    /// Returns the number of goods stored by `self`, or [`None`] if unknown.
+    fn load(&self) -> Option<usize>;
+}
+
+/// Wraps a [`Producer`] so that it can be a [`Member`] of a [`LoadBalancer`] with defects of type `D`.
+struct Balanced<P, D> {
+    /// The actual producer.
+    producer: P,
+    /// Returns the load of `producer`.
+    gauge: fn(&P) -> Option<usize>,
+    /// The type of the defect.
+    _defect: PhantomData<D>,
+}
+
+impl<P: Agent, D> Agent for Balanced<P, D> {
+    type Good = P::Good;
+}
+
+impl<P: Display, D> Display for Balanced<P, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.producer)
+    }
+}
+
+impl<P, D> Producer for Balanced<P, D>
+where
+    P: Producer,
+    FullStock: From<<P::Flaws as Flaws>::Insufficiency>,
+    D: From<<P::Flaws as Flaws>::Defect>,
+{
+    type Flaws = ProductionFlaws<D>;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        self.producer
+            .produce(good)
+            .map_err(|recall| recall.blame())?;
+    }
+}
+
+impl<P, D> Member for Balanced<P, D>
+where
+    P: Producer,
+    FullStock: From<<P::Flaws as Flaws>::Insufficiency>,
+    D: From<<P::Flaws as Flaws>::Defect>,
+{
+    fn load(&self) -> Option<usize> {
+        (self.gauge)(&self.producer)
+    }
+}
+
+/// Specifies how a [`LoadBalancer`] selects the [`Producer`] of a good.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Balance {
+    /// Selects each [`Producer`] in turn.
+    RoundRobin,
+    /// Selects the [`Producer`] that stores the fewest goods.
+    ///
+    /// [`Producer`]s that do not report their load are selected after all those that do, in turn.
+    LeastLoaded,
+}
+
+/// A [`Producer`] that produces each good of type `G` to one of multiple interchangeable [`Producer`]s with defects of type `D`.
+///
+/// If the selected [`Producer`] throws [`FullStock`], the good is produced to the next [`Producer`]; [`FullStock`] is only thrown when every [`Producer`] is full.
+pub struct LoadBalancer<G, D> {
+    /// The [`Producer`]s, in the order they were pushed.
+    producers: Vec<Box<dyn Member<Good = G, Flaws = ProductionFlaws<D>>>>,
+    /// How the [`Producer`] of a good is selected.
+    balance: Balance,
+    /// The index of the [`Producer`] that is selected first when producing the next good.
+    next: Cell<usize>,
+    /// The name of the balancer.
+    name: String,
+}
+
+impl<G: 'static, D: 'static> LoadBalancer<G, D> {
+    /// Creates a new, empty [`LoadBalancer`] that selects [`Producer`]s according to `balance`.
+    #[must_use]
+    pub fn new<S>(name_str: &S, balance: Balance) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            producers: Vec::new(),
+            balance,
+            next: Cell::new(0),
+            name: String::from(name_str.as_ref()),
+        }
+    }
+
+    /// Adds `producer`, which does not report its load, to `self`.
+    pub fn push<P: Producer<Good = G> + 'static>(&mut self, producer: P)
+    where
+        FullStock: From<<P::Flaws as Flaws>::Insufficiency>,
+        D: From<<P::Flaws as Flaws>::Defect>,
+    {
+        self.producers.push(Box::new(Balanced {
+            producer,
+            gauge: |_| None,
+            _defect: PhantomData,
+        }));
+    }
+
+    /// Adds `producer` to `self`, reporting its load via [`Inventory::len()`].
+    pub fn push_gauged<P: Producer<Good = G> + Inventory + 'static>(&mut self, producer: P)
+    where
+        FullStock: From<<P::Flaws as Flaws>::Insufficiency>,
+        D: From<<P::Flaws as Flaws>::Defect>,
+    {
+        self.producers.push(Box::new(Balanced {
+            producer,
+            gauge: |producer| Some(producer.len()),
+            _defect: PhantomData,
+        }));
+    }
+
+    /// Returns the indices of the [`Producer`]s in the order they shall be attempted.
+    fn order(&self) -> Vec<usize> {
+        let count = self.producers.len();
+        let first = self.next.get();
+        let mut order: Vec<usize> = (0..count).map(|i| (first + i) % count).collect();
+
+        if self.balance == Balance::LeastLoaded {
+            order.sort_by_key(|&index| {
+                self.producers
+                    .get(index)
+                    .and_then(|producer| producer.load())
+                    .unwrap_or(usize::MAX)
+            });
+        }
+
+        order
+    }
+}
+
+impl<G, D> Agent for LoadBalancer<G, D> {
+    type Good = G;
+}
+
+impl<G, D> Debug for LoadBalancer<G, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "LoadBalancer {{ balance: {:?}, .. }}", self.balance)
+    }
+}
+
+impl<G, D> Display for LoadBalancer<G, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G: 'static, D: 'static> Producer for LoadBalancer<G, D> {
+    type Flaws = ProductionFlaws<D>;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        let mut remaining = Some(good);
+
+        for (index, producer) in self
+            .order()
+            .into_iter()
+            .filter_map(|index| self.producers.get(index).map(|producer| (index, producer)))
+        {
+            if let Some(good) = remaining.take() {
+                match producer.produce(good) {
+                    Ok(()) => {
+                        self.next.set(index.wrapping_add(1) % self.producers.len());
+                    }
+                    Err(recall) => match TryBlame::<Recall<DefectFlaws<D>, G>>::try_blame(recall) {
+                        Ok(defect) => throw!(defect.blame()),
+                        Err(error) => remaining = Some(error.into_good()),
+                    },
+                }
+            }
+        }
+
+        if let Some(good) = remaining {
+            throw!(self.recall(Fault::Insufficiency(FullStock::default()), good));
+        }
+    }
+}
+
+/// A [`Consumer`] held by a [`Collector`].
+type Collected<G, F> = Box<dyn Consumer<Good = G, Flaws = F>>;
+
+/// Identifies a [`Consumer`] held by a [`Collector`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ConsumerId(usize);
+
+/// Specifies the order in which a [`Collector`] consumes from its [`Consumer`]s.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Fairness {
+    /// Consumes from the first [`Consumer`] that has a good, in the order the [`Consumer`]s were pushed.
+    ///
+    /// A [`Consumer`] is only consumed from when every [`Consumer`] before it is empty.
+    Priority,
+    /// Consumes from the first [`Consumer`] that has a good, starting after the last [`Consumer`] that supplied a good.
+    RoundRobin,
+    /// Consumes up to the weight of each [`Consumer`] in turn, moving on early when the [`Consumer`] is empty.
+    Weighted,
+}
+
+#[allow(clippy::derivable_impls)] // Deriving Default for an enum requires a newer compiler.
+impl Default for Fairness {
+    fn default() -> Self {
+        Self::Priority
+    }
+}
+
+/// The maximum number of defects recorded by a tolerant [`Collector`] before they are taken.
+const DEFECT_CAPACITY: usize = 64;
+
+/// A [`Consumer`] held by a [`Collector`] along with its properties.
+struct Source<G, F> {
+    /// The id of the [`Consumer`].
+    id: ConsumerId,
+    /// The number of consecutive goods consumed from the [`Consumer`] per turn when using [`Fairness::Weighted`].
+    weight: usize,
+    /// The number of consecutive defects thrown by the [`Consumer`].
+    defect_count: usize,
+    /// If the [`Consumer`] is skipped due to repeated defects.
+    is_quarantined: bool,
+    /// The [`Consumer`].
+    consumer: Collected<G, F>,
+}
+
+/// A [`Consumer`] that consumes goods of type `G` from multiple [`Consumer`]s.
+pub struct Collector<G, F: Flaws> {
+    /// The [`Consumer`]s.
+    sources: RefCell<Vec<Source<G, F>>>,
+    /// The order in which the [`Consumer`]s are consumed from.
+    fairness: Fairness,
+    /// The index of the [`Consumer`] that is consumed from first.
+    cursor: Cell<usize>,
+    /// The number of goods consumed from the [`Consumer`] at `cursor` in its current turn.
+    credit: Cell<usize>,
+    /// The id of the next [`Consumer`] pushed to `self`.
+    next_id: usize,
+    /// If a [`Consumer`] that throws a defect is removed.
+    is_pruning: bool,
+    /// If a defect thrown by a [`Consumer`] is recorded instead of thrown.
+    is_tolerant: bool,
+    /// The number of consecutive defects after which a [`Consumer`] is quarantined.
+    quarantine_limit: Option<usize>,
+    /// The defects recorded while tolerant, along with the id of the [`Consumer`] that threw each defect.
+    defects: RefCell<Vec<(ConsumerId, Failure<F>)>>,
+    /// The name of the collector.
+    name: String,
+}
+
+impl<G, F: Flaws> Collector<G, F> {
+    /// Creates a new, empty [`Collector`].
+    #[must_use]
+    pub fn new(name: String) -> Self {
+        Self {
+            sources: RefCell::new(Vec::new()),
+            fairness: Fairness::default(),
+            cursor: Cell::new(0),
+            credit: Cell::new(0),
+            next_id: 0,
+            is_pruning: false,
+            is_tolerant: false,
+            quarantine_limit: None,
+            defects: RefCell::new(Vec::new()),
+            name,
+        }
+    }
+
+    /// Configures the order in which `self` consumes from its [`Consumer`]s.
+    #[must_use]
+    pub fn with_fairness(mut self, fairness: Fairness) -> Self {
+        self.fairness = fairness;
+        self
+    }
+
+    /// Configures `self` to remove each [`Consumer`] that throws a defect, such as a [`Consumer`] whose supply has withdrawn.
+    ///
+    /// A pruning [`Collector`] only throws a defect when it removes its last [`Consumer`]. Pruning takes precedence over [`Collector::with_defect_tolerance()`] and [`Collector::with_quarantine()`]: a defect removes the [`Consumer`] instead of being recorded.
+    #[must_use]
+    pub fn with_pruning(mut self) -> Self {
+        self.is_pruning = true;
+        self
+    }
+
+    /// Configures `self` to record each defect thrown by a [`Consumer`] and continue consuming from the next [`Consumer`], instead of throwing the defect.
+    ///
+    /// The recorded defects are retrieved by [`Collector::take_defects()`]. At most 64 defects are held until they are taken; further defects are discarded. A tolerant [`Collector`] that has no goods throws an insufficiency even if every [`Consumer`] threw a defect.
+    ///
+    /// Has no effect if `self` is pruning, see [`Collector::with_pruning()`].
+    #[must_use]
+    pub fn with_defect_tolerance(mut self) -> Self {
+        self.is_tolerant = true;
+        self
+    }
+
+    /// Configures `self` to tolerate defects and to quarantine each [`Consumer`] that throws `limit` consecutive defects.
+    ///
+    /// A quarantined [`Consumer`] is not consumed from until it is released by [`Collector::release()`]. Has no effect if `self` is pruning, see [`Collector::with_pruning()`].
+    #[must_use]
+    pub fn with_quarantine(mut self, limit: usize) -> Self {
+        self.is_tolerant = true;
+        self.quarantine_limit = Some(limit);
+        self
+    }
+
+    /// Removes and returns the defects recorded while tolerant, along with the id of the [`Consumer`] that threw each defect.
+    pub fn take_defects(&self) -> Vec<(ConsumerId, Failure<F>)> {
+        self.defects.replace(Vec::new())
+    }
+
+    /// Returns the ids of the quarantined [`Consumer`]s.
+    pub fn quarantined(&self) -> Vec<ConsumerId> {
+        self.sources
+            .borrow()
+            .iter()
+            .filter(|source| source.is_quarantined)
+            .map(|source| source.id)
+            .collect()
+    }
+
+    /// Releases the [`Consumer`] identified by `id` from quarantine, returning if it was quarantined.
+    pub fn release(&mut self, id: ConsumerId) -> bool {
+        if let Some(source) = self
+            .sources
+            .get_mut()
+            .iter_mut()
+            .find(|source| source.id == id && source.is_quarantined)
+        {
+            source.is_quarantined = false;
+            source.defect_count = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Adds `consumer` to the end of the [`Consumer`]s held by `self`.
+    pub fn push<C: Consumer + 'static>(&mut self, consumer: C)
+    where
+        F: 'static,
+        G: From<C::Good> + 'static,
+        F::Insufficiency: From<<C::Flaws as Flaws>::Insufficiency>,
+        F::Defect: From<<C::Flaws as Flaws>::Defect>,
+    {
+        let _ = self.push_with_id(consumer);
+    }
+
+    /// Adds `consumer` with a weight of `1` to the end of the [`Consumer`]s held by `self`, returning the id of `consumer`.
+    pub fn push_with_id<C: Consumer + 'static>(&mut self, consumer: C) -> ConsumerId
+    where
+        F: 'static,
+        G: From<C::Good> + 'static,
+        F::Insufficiency: From<<C::Flaws as Flaws>::Insufficiency>,
+        F::Defect: From<<C::Flaws as Flaws>::Defect>,
+    {
+        self.push_weighted(consumer, 1)
+    }
+
+    /// Adds `consumer` with `weight` to the end of the [`Consumer`]s held by `self`, returning the id of `consumer`.
+    ///
+    /// `weight` is only used by [`Fairness::Weighted`]; a `weight` of `0` is treated as `1`.
+    pub fn push_weighted<C: Consumer + 'static>(&mut self, consumer: C, weight: usize) -> ConsumerId
+    where
+        F: 'static,
+        G: From<C::Good> + 'static,
+        F::Insufficiency: From<<C::Flaws as Flaws>::Insufficiency>,
+        F::Defect: From<<C::Flaws as Flaws>::Defect>,
+    {
+        self.push_collected(Box::new(Adapter::new(consumer)), weight)
+    }
+
+    /// Adds `consumer` with `weight` to the end of the [`Consumer`]s held by `self`, returning the id of `consumer`.
+    fn push_collected(&mut self, consumer: Collected<G, F>, weight: usize) -> ConsumerId {
+        let id = ConsumerId(self.next_id);
+
+        self.next_id = self.next_id.wrapping_add(1);
+        self.sources.get_mut().push(Source {
+            id,
+            weight: cmp::max(weight, 1),
+            defect_count: 0,
+            is_quarantined: false,
+            consumer,
+        });
+        id
+    }
+
+    /// Removes the [`Consumer`] identified by `id` from `self`, returning the [`Consumer`].
+    pub fn remove(&mut self, id: ConsumerId) -> Option<Collected<G, F>> {
+        let index = self
+            .sources
+            .get_mut()
+            .iter()
+            .position(|source| source.id == id)?;
+
+        self.retreat(index);
+        Some(self.sources.get_mut().remove(index).consumer)
+    }
+
+    /// Replaces the [`Consumer`] identified by `id` with `consumer`, returning the replaced [`Consumer`].
+    ///
+    /// `consumer` keeps the weight of the replaced [`Consumer`] and is not quarantined.
+    fn replace(&mut self, id: ConsumerId, consumer: Collected<G, F>) -> Option<Collected<G, F>> {
+        self.sources
+            .get_mut()
+            .iter_mut()
+            .find(|source| source.id == id)
+            .map(|source| {
+                source.defect_count = 0;
+                source.is_quarantined = false;
+                core::mem::replace(&mut source.consumer, consumer)
+            })
+    }
+
+    /// Returns if `self` holds the [`Consumer`] identified by `id`.
+    pub fn contains(&self, id: ConsumerId) -> bool {
+        self.sources.borrow().iter().any(|source| source.id == id)
+    }
+
+    /// Returns the ids of the [`Consumer`]s held by `self`, in the order they were pushed.
+    pub fn ids(&self) -> Vec<ConsumerId> {
+        self.sources
+            .borrow()
+            .iter()
+            .map(|source| source.id)
+            .collect()
+    }
+
+    /// Returns the number of [`Consumer`]s held by `self`.
+    pub fn len(&self) -> usize {
+        self.sources.borrow().len()
+    }
+
+    /// Returns if `self` holds no [`Consumer`]s.
+    pub fn is_empty(&self) -> bool {
+        self.sources.borrow().is_empty()
+    }
+
+    /// Returns the index of the [`Consumer`] that shall be consumed from first.
+    fn start(&self) -> usize {
+        match self.fairness {
+            Fairness::Priority => 0,
+            Fairness::RoundRobin | Fairness::Weighted => self.cursor.get(),
+        }
+    }
+
+    /// Records that the [`Consumer`] at `index` with `weight` supplied a good.
+    fn advance(&self, index: usize, weight: usize) {
+        match self.fairness {
+            Fairness::Priority => {}
+            Fairness::RoundRobin => self.cursor.set(index.wrapping_add(1)),
+            Fairness::Weighted => {
+                let credit = if index == self.cursor.get() {
+                    self.credit.get().saturating_add(1)
+                } else {
+                    1
+                };
+
+                if credit >= weight {
+                    self.cursor.set(index.wrapping_add(1));
+                    self.credit.set(0);
+                } else {
+                    self.cursor.set(index);
+                    self.credit.set(credit);
+                }
+            }
+        }
+    }
+
+    /// Keeps the turn of the [`Consumer`] at the cursor when the [`Consumer`] at `index` is removed.
+    fn retreat(&self, index: usize) {
+        let cursor = self.cursor.get();
+
+        if index < cursor {
+            self.cursor.set(cursor - 1);
+        } else if index == cursor {
+            self.credit.set(0);
+        }
+    }
+}
+
+impl<G, F: Flaws> Agent for Collector<G, F> {
+    type Good = G;
+}
+
+impl<G, F: Flaws> Consumer for Collector<G, F>
+where
+    F::Defect: Flaws,
+    EmptyStock: From<F::Insufficiency>,
+    <F::Defect as Flaws>::Defect: From<F::Defect>,
+{
+    type Flaws = ConsumptionFlaws<F::Defect>;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let mut sources = self.sources.borrow_mut();
+        let mut result: Result<Self::Good, Failure<Self::Flaws>> =
+            Err(self.failure(Fault::Insufficiency(EmptyStock::default())));
+        let mut remaining = sources.len();
+        let mut index = self.start();
+
+        while remaining > 0 {
+            if index >= sources.len() {
+                index = 0;
+            }
+
+            let source = if let Some(source) = sources.get_mut(index) {
+                source
+            } else {
+                break;
+            };
+
+            remaining -= 1;
+
+            if source.is_quarantined {
+                index += 1;
+                continue;
+            }
+
+            match source.consumer.consume() {
+                Ok(good) => {
+                    source.defect_count = 0;
+                    let weight = source.weight;
+                    self.advance(index, weight);
+                    result = Ok(good);
+                    break;
+                }
+                Err(failure) => {
+                    if !failure.is_defect() {
+                        source.defect_count = 0;
+                        result = Err(failure.blame());
+                        index += 1;
+                    } else if self.is_pruning {
+                        self.retreat(index);
+                        let _ = sources.remove(index);
+
+                        if sources.is_empty() {
+                            result = Err(failure.blame());
+                        }
+                    } else if self.is_tolerant {
+                        source.defect_count = source.defect_count.saturating_add(1);
+
+                        if matches!(self.quarantine_limit, Some(limit) if source.defect_count >= limit)
+                        {
+                            source.is_quarantined = true;
+                        }
+
+                        let mut defects = self.defects.borrow_mut();
+
+                        if defects.len() < DEFECT_CAPACITY {
+                            defects.push((source.id, failure));
+                        }
+
+                        index += 1;
+                    } else {
+                        result = Err(failure.blame());
+                        break;
+                    }
+                }
+            }
+        }
+
+        result?
+    }
+}
+
+impl<G, F: Flaws> Debug for Collector<G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Collector {{ .. }}")
+    }
+}
+
+impl<G, F: Flaws> Display for Collector<G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Collector for {}", self.name)
+    }
+}
+
+/// Compares the goods merged by a [`MergeCollector`].
+type MergeOrder<G> = Box<dyn Fn(&G, &G) -> Ordering>;
+
+/// A [`Consumer`] held by a [`MergeCollector`] along with its next good.
+struct MergeSource<G, F: Flaws> {
+    /// The [`Consumer`].
+    consumer: Collected<G, F>,
+    /// The good consumed from `consumer` that has not been merged.
+    head: Option<G>,
+    /// When `consumer` was first found empty since it last supplied a good.
+    empty_since: Option<Instant>,
+    /// If `consumer` has thrown a defect since it last supplied a good, while which `consumer` is not consumed from.
+    has_failed: bool,
+}
+
+/// A [`Consumer`] that merges the goods of type `G` from multiple [`Consumer`]s that each supply goods in order.
+///
+/// The collector holds the next good of each [`Consumer`] and consumes the lowest of them, so the goods are consumed in order as long as each [`Consumer`] supplies its goods in order. While a [`Consumer`] is empty, its next good may be lower than the held goods, so the collector waits for it by throwing [`EmptyStock`]; a timeout limits how long the collector waits for a silent [`Consumer`]. When a [`Consumer`] throws a defect, the collector throws that defect and no longer consumes from that [`Consumer`]; once every [`Consumer`] has thrown a defect and the held goods are consumed, the collector consumes from every [`Consumer`] again and throws the first defect.
+pub struct MergeCollector<G, F: Flaws> {
+    /// The [`Consumer`]s.
+    sources: RefCell<Vec<MergeSource<G, F>>>,
+    /// Compares goods.
+    order: MergeOrder<G>,
+    /// How long an empty [`Consumer`] is waited for, or [`None`] to wait until it supplies a good or withdraws.
+    timeout: Option<Duration>,
+    /// The name of the collector.
+    name: String,
+}
+
+impl<G: 'static, F: Flaws + 'static> MergeCollector<G, F> {
+    /// Creates a new, empty [`MergeCollector`] that merges goods in their natural order.
+    #[must_use]
+    pub fn new<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        G: Ord,
+    {
+        Self::with_order(name_str, Box::new(G::cmp))
+    }
+
+    /// Creates a new, empty [`MergeCollector`] that merges goods in the order of the keys computed by `key`.
+    #[must_use]
+    pub fn with_key<S, K, R>(name_str: &S, key: R) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        K: Ord,
+        R: Fn(&G) -> K + 'static,
+    {
+        Self::with_order(name_str, Box::new(move |a, b| key(a).cmp(&key(b))))
+    }
+
+    /// Creates a new, empty [`MergeCollector`] that merges goods according to `order`.
+    fn with_order<S>(name_str: &S, order: MergeOrder<G>) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            sources: RefCell::new(Vec::new()),
+            order,
+            timeout: None,
+            name: String::from(name_str.as_ref()),
+        }
+    }
+
+    /// Configures `self` to stop waiting for a [`Consumer`] once it has been empty for `timeout`.
+    ///
+    /// A good that a [`Consumer`] supplies after its timeout may be consumed after higher goods.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds `consumer` to the [`Consumer`]s merged by `self`.
+    pub fn push<C: Consumer + 'static>(&mut self, consumer: C)
+    where
+        G: From<C::Good>,
+        F::Insufficiency: From<<C::Flaws as Flaws>::Insufficiency>,
+        F::Defect: From<<C::Flaws as Flaws>::Defect>,
+    {
+        self.sources.get_mut().push(MergeSource {
+            consumer: Box::new(Adapter::new(consumer)),
+            head: None,
+            empty_since: None,
+            has_failed: false,
+        });
+    }
+}
+
+impl<G, F: Flaws> Agent for MergeCollector<G, F> {
+    type Good = G;
+}
+
+impl<G, F: Flaws> Consumer for MergeCollector<G, F>
+where
+    EmptyStock: From<F::Insufficiency>,
+{
+    type Flaws = ConsumptionFlaws<F::Defect>;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let mut sources = self.sources.borrow_mut();
+        let now = Instant::now();
+        let mut is_waiting = false;
+        let is_exhausted = sources.iter().all(|source| source.has_failed);
+
+        for source in sources
+            .iter_mut()
+            .filter(|source| source.head.is_none() && (is_exhausted || !source.has_failed))
+        {
+            match source.consumer.consume() {
+                Ok(good) => {
+                    source.head = Some(good);
+                    source.empty_since = None;
+                    source.has_failed = false;
+                }
+                Err(failure) => {
+                    if failure.is_defect() {
+                        source.has_failed = true;
+                        throw!(failure.blame());
+                    } else {
+                        source.has_failed = false;
+
+                        let empty_since = *source.empty_since.get_or_insert(now);
+
+                        if !matches!(self.timeout, Some(timeout) if now.saturating_duration_since(empty_since) >= timeout)
+                        {
+                            is_waiting = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        if is_waiting {
+            throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+        }
+
+        let mut lowest: Option<&mut MergeSource<G, F>> = None;
+
+        for source in sources.iter_mut().filter(|source| source.head.is_some()) {
+            lowest = match lowest {
+                Some(current) if !self.precedes(source, current) => Some(current),
+                _ => Some(source),
+            };
+        }
+
+        if let Some(good) = lowest.and_then(|source| source.head.take()) {
+            good
+        } else {
+            throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+        }
+    }
+}
+
+impl<G, F: Flaws> MergeCollector<G, F> {
+    /// Returns if the head of `source` is lower than the head of `other`.
+    fn precedes(&self, source: &MergeSource<G, F>, other: &MergeSource<G, F>) -> bool {
+        match (source.head.as_ref(), other.head.as_ref()) {
+            (Some(good), Some(other_good)) => (self.order)(good, other_good) == Ordering::Less,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+impl<G, F: Flaws> Debug for MergeCollector<G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "MergeCollector {{ timeout: {:?}, .. }}", self.timeout)
+    }
+}
+
+impl<G, F: Flaws> Display for MergeCollector<G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A [`Consumer`] that tags each good of a [`Consumer`] with the key of the [`Consumer`].
+struct Tagged<K, G, F> {
+    /// The key.
+    key: K,
+    /// The [`Consumer`].
+    consumer: Collected<G, F>,
+}
+
+impl<K, G, F> Agent for Tagged<K, G, F> {
+    type Good = (K, G);
+}
+
+impl<K: Clone, G, F: Flaws> Consumer for Tagged<K, G, F> {
+    type Flaws = F;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        (self.key.clone(), self.consumer.consume()?)
+    }
+}
+
+impl<K, G, F> Display for Tagged<K, G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.consumer)
+    }
+}
+
+/// A [`Consumer`] that consumes goods of type `G` from multiple [`Consumer`]s, tagging each good with the key of its [`Consumer`].
+///
+/// The [`Consumer`]s are held by a [`Collector`], which determines the order in which they are consumed from and how their defects are handled.
+pub struct KeyedCollector<K, G, F: Flaws> {
+    /// The [`Collector`] that holds the [`Consumer`]s.
+    collector: Collector<(K, G), F>,
+    /// The ids of the [`Consumer`]s held by `collector`, by key.
+    ids: HashMap<K, ConsumerId>,
+    /// The keys of the [`Consumer`]s held by `collector`, by id.
+    keys: HashMap<ConsumerId, K>,
+    /// The name of the collector.
+    name: String,
+}
+
+impl<K, G, F: Flaws> KeyedCollector<K, G, F> {
+    /// Creates a new, empty [`KeyedCollector`].
+    #[must_use]
+    pub fn new<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            collector: Collector::new(String::from(name_str.as_ref())),
+            ids: HashMap::new(),
+            keys: HashMap::new(),
+            name: String::from(name_str.as_ref()),
+        }
+    }
+
+    /// Configures the order in which `self` consumes from its [`Consumer`]s.
+    #[must_use]
+    pub fn with_fairness(mut self, fairness: Fairness) -> Self {
+        self.collector = self.collector.with_fairness(fairness);
+        self
+    }
+
+    /// Configures `self` to remove each [`Consumer`] that throws a defect, see [`Collector::with_pruning()`].
+    #[must_use]
+    pub fn with_pruning(mut self) -> Self {
+        self.collector = self.collector.with_pruning();
+        self
+    }
+
+    /// Configures `self` to record each defect thrown by a [`Consumer`] instead of throwing the defect, see [`Collector::with_defect_tolerance()`].
+    #[must_use]
+    pub fn with_defect_tolerance(mut self) -> Self {
+        self.collector = self.collector.with_defect_tolerance();
+        self
+    }
+
+    /// Configures `self` to tolerate defects and to quarantine each [`Consumer`] that throws `limit` consecutive defects, see [`Collector::with_quarantine()`].
+    #[must_use]
+    pub fn with_quarantine(mut self, limit: usize) -> Self {
+        self.collector = self.collector.with_quarantine(limit);
+        self
+    }
+
+    /// Inserts a mapping of `consumer` to `key` into `self`, returning the [`Consumer`] it replaced, which tags its goods with `key`.
+    ///
+    /// A new key is consumed from after the existing keys.
+    pub fn insert<C: Consumer + 'static>(
+        &mut self,
+        key: K,
+        consumer: C,
+    ) -> Option<Collected<(K, G), F>>
+    where
+        K: Clone + Eq + Hash + 'static,
+        G: From<C::Good> + 'static,
+        F: 'static,
+        F::Insufficiency: From<<C::Flaws as Flaws>::Insufficiency>,
+        F::Defect: From<<C::Flaws as Flaws>::Defect>,
+    {
+        self.prune();
+        let tagged: Collected<(K, G), F> = Box::new(Tagged {
+            key: key.clone(),
+            consumer: Box::new(Adapter::new(consumer)),
+        });
+
+        if let Some(id) = self.ids.get(&key) {
+            self.collector.replace(*id, tagged)
+        } else {
+            let id = self.collector.push_collected(tagged, 1);
+
+            let _ = self.keys.insert(id, key.clone());
+            let _ = self.ids.insert(key, id);
+            None
+        }
+    }
+
+    /// Removes the mapping of `key` from `self`, returning the [`Consumer`] that was mapped to `key`, which tags its goods with `key`.
+    pub fn remove(&mut self, key: &K) -> Option<Collected<(K, G), F>>
+    where
+        K: Eq + Hash,
+    {
+        self.prune();
+        let id = self.ids.remove(key)?;
+
+        let _ = self.keys.remove(&id);
+        self.collector.remove(id)
+    }
+
+    /// Returns if a [`Consumer`] is mapped to `key`.
+    pub fn contains_key(&self, key: &K) -> bool
+    where
+        K: Eq + Hash,
+    {
+        matches!(self.ids.get(key), Some(id) if self.collector.contains(*id))
+    }
+
+    /// Returns an iterator over the keys that are mapped to a [`Consumer`], in the order they were inserted.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.collector
+            .ids()
+            .into_iter()
+            .filter_map(move |id| self.key(id))
+    }
+
+    /// Returns the number of [`Consumer`]s mapped to a key.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.collector.len()
+    }
+
+    /// Returns if no [`Consumer`] is mapped to a key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.collector.is_empty()
+    }
+
+    /// Removes and returns the defects recorded while tolerant, along with the key of the [`Consumer`] that threw each defect.
+    ///
+    /// The defects of [`Consumer`]s that are no longer mapped to a key are discarded.
+    pub fn take_defects(&self) -> Vec<(K, Failure<F>)>
+    where
+        K: Clone,
+    {
+        self.collector
+            .take_defects()
+            .into_iter()
+            .filter_map(|(id, failure)| self.key(id).map(|key| (key.clone(), failure)))
+            .collect()
+    }
+
+    /// Returns the keys of the quarantined [`Consumer`]s.
+    pub fn quarantined(&self) -> Vec<&K> {
+        self.collector
+            .quarantined()
+            .into_iter()
+            .filter_map(|id| self.key(id))
+            .collect()
+    }
+
+    /// Releases the [`Consumer`] mapped to `key` from quarantine, returning if it was quarantined.
+    pub fn release(&mut self, key: &K) -> bool
+    where
+        K: Eq + Hash,
+    {
+        self.prune();
+
+        if let Some(id) = self.ids.get(key) {
+            self.collector.release(*id)
+        } else {
+            false
+        }
+    }
+
+    /// Returns the key of the [`Consumer`] identified by `id`.
+    fn key(&self, id: ConsumerId) -> Option<&K> {
+        self.keys.get(&id)
+    }
+
+    /// Removes the keys of the [`Consumer`]s that were pruned by the [`Collector`].
+    fn prune(&mut self)
+    where
+        K: Eq + Hash,
+    {
+        let collector = &self.collector;
+        let ids = &mut self.ids;
+
+        self.keys.retain(|id, key| {
+            if collector.contains(*id) {
+                true
+            } else {
+                let _ = ids.remove(key);
+                false
+            }
+        });
+    }
+}
+
+impl<K, G, F: Flaws> Agent for KeyedCollector<K, G, F> {
+    type Good = (K, G);
+}
+
+impl<K, G, F: Flaws> Consumer for KeyedCollector<K, G, F>
+where
+    F::Defect: Flaws,
+    EmptyStock: From<F::Insufficiency>,
+    <F::Defect as Flaws>::Defect: From<F::Defect>,
+{
+    type Flaws = ConsumptionFlaws<F::Defect>;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        match self.collector.consume() {
+            Ok(good) => good,
+            Err(failure) if failure.is_defect() => throw!(failure),
+            Err(_) => throw!(self.failure(Fault::Insufficiency(EmptyStock::default()))),
+        }
+    }
+}
+
+impl<K, G, F: Flaws> Debug for KeyedCollector<K, G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "KeyedCollector {{ .. }}")
+    }
+}
+
+impl<K, G, F: Flaws> Display for KeyedCollector<K, G, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
     }
 }
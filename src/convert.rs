@@ -2,11 +2,14 @@
 use {
     core::{
         convert::TryFrom,
-        fmt::{self, Display, Formatter},
+        fmt::{self, Debug, Display, Formatter},
         marker::PhantomData,
     },
     fehler::{throw, throws},
-    market::{Agent, Blame, Consumer, Failure, Fault, Flaws, Producer, Recall},
+    market::{
+        Agent, Blame, Consumer, EmptyStock, Failure, Fault, Flaws, FullStock, Producer, Recall,
+        TryBlame,
+    },
 };
 
 /// A defect thrown when an attempt to specify and produce fails.
@@ -17,6 +20,14 @@ pub enum SpecificationDefect<D> {
     Unspecifiable,
     /// The specified good could not be produced.
     Improducible(D),
+    /// The specified good was recalled due to a defect that could not be converted.
+    Recalled,
+}
+
+impl<D> From<D> for SpecificationDefect<D> {
+    fn from(defect: D) -> Self {
+        Self::Improducible(defect)
+    }
 }
 
 /// The [`Flaws`] defining errors thrown when a specifier attempts to specify and produce a good on a [`Producer`] with [`Flaws`] `F`.
@@ -158,3 +169,412 @@ where
         write!(f, "Adapter for {}", self.consumer)
     }
 }
+
+/// A fault of type `T` that has been discarded.
+struct Discarded<T>(PhantomData<T>);
+
+impl<T> From<T> for Discarded<T> {
+    fn from(_: T) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> From<Discarded<T>> for EmptyStock {
+    fn from(_: Discarded<T>) -> Self {
+        Self::default()
+    }
+}
+
+/// A fault of type `T` that cannot be converted from a [`Discarded`] fault.
+struct Refused<T>(PhantomData<T>);
+
+impl<T> TryFrom<Discarded<T>> for Refused<T> {
+    type Error = ();
+
+    #[throws(())]
+    fn try_from(_: Discarded<T>) -> Self {
+        throw!(());
+    }
+}
+
+/// The [`Flaws`] into which every fault of [`Flaws`] `F` converts.
+type Discarding<F> =
+    MappedFlaws<Discarded<<F as Flaws>::Insufficiency>, Discarded<<F as Flaws>::Defect>>;
+
+/// The [`Flaws`] into which only an insufficiency of [`Discarding<F>`] converts.
+type DefectRefusal<F> =
+    MappedFlaws<Discarded<<F as Flaws>::Insufficiency>, Refused<<F as Flaws>::Defect>>;
+
+/// The [`Flaws`] into which no fault of [`DefectRefusal<F>`] that is an insufficiency converts.
+type Refusal<F> = MappedFlaws<Refused<<F as Flaws>::Insufficiency>, Refused<<F as Flaws>::Defect>>;
+
+/// Takes the good out of `recall`, returning the good and if `recall` was caused by a defect.
+///
+/// A [`Recall`] only releases its good when its fault fails to convert, so `recall` is converted into [`DefectRefusal`], which only fails for a defect, and then into [`Refusal`].
+fn reclaim<F: Flaws, G>(recall: Recall<F, G>) -> (G, bool) {
+    let discarded: Recall<Discarding<F>, G> = recall.blame();
+
+    match TryBlame::<Recall<DefectRefusal<F>, G>>::try_blame(discarded) {
+        Ok(insufficiency) => match TryBlame::<Recall<Refusal<F>, G>>::try_blame(insufficiency) {
+            Ok(_) => {
+                unreachable!("converting a discarded insufficiency into a refused insufficiency")
+            }
+            Err(error) => (error.into_good(), false),
+        },
+        Err(error) => (error.into_good(), true),
+    }
+}
+
+/// Returns the fault of a specifier with [`Flaws`] `F` that replaces a fault of its [`Producer`].
+fn specification_fault<F: Flaws>(is_defect: bool) -> Fault<SpecificationFlaws<F>>
+where
+    F::Insufficiency: From<FullStock>,
+{
+    if is_defect {
+        Fault::Defect(SpecificationDefect::Recalled)
+    } else {
+        Fault::Insufficiency(FullStock::default().into())
+    }
+}
+
+/// A [`Producer`] that converts its good with closures for the producer `P`.
+///
+/// Converts the generic good `G` to a specific good `P::Good` with `map` and converts the good recalled by `P` back to `G` with `unmap`.
+///
+/// A [`Recall`] never releases its fault and only converts it via [`From`] along with its good, so the fault thrown by `P` cannot be mapped with a closure. Instead, an insufficiency of `P` is thrown as [`FullStock`] and a defect of `P` as [`SpecificationDefect::Recalled`].
+pub struct MapSpecifier<P, G, F, M, U> {
+    /// The actual producer.
+    producer: P,
+    /// Converts the generic good into the specific good.
+    map: M,
+    /// Converts the specific good into the generic good.
+    unmap: U,
+    /// The type of the original good.
+    _good: PhantomData<G>,
+    /// The type of self's [`Flaws`].
+    _flaws: PhantomData<F>,
+}
+
+impl<P, G, F, M, U> MapSpecifier<P, G, F, M, U> {
+    /// Creates a new [`MapSpecifier`] that converts each good with `map` and each recalled good with `unmap`.
+    pub const fn new(producer: P, map: M, unmap: U) -> Self {
+        Self {
+            producer,
+            map,
+            unmap,
+            _good: PhantomData,
+            _flaws: PhantomData,
+        }
+    }
+}
+
+impl<P, G, F, M, U> Agent for MapSpecifier<P, G, F, M, U>
+where
+    P: Producer,
+{
+    type Good = G;
+}
+
+impl<P: Debug, G, F, M, U> Debug for MapSpecifier<P, G, F, M, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "MapSpecifier {{ producer: {:?}, .. }}", self.producer)
+    }
+}
+
+impl<P, G, F, M, U> Display for MapSpecifier<P, G, F, M, U>
+where
+    P: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Specifier for {}", self.producer)
+    }
+}
+
+impl<P, G, F, M, U> Producer for MapSpecifier<P, G, F, M, U>
+where
+    P: Producer,
+    F: Flaws,
+    M: Fn(G) -> P::Good,
+    U: Fn(P::Good) -> G,
+    F::Insufficiency: From<FullStock>,
+{
+    type Flaws = SpecificationFlaws<F>;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        if let Err(recall) = self.producer.produce((self.map)(good)) {
+            let (specific_good, is_defect) = reclaim(recall);
+
+            throw!(self.recall(specification_fault(is_defect), (self.unmap)(specific_good)));
+        }
+    }
+}
+
+/// A [`Producer`] that converts its good with a closure that may reject the good for the producer `P`.
+///
+/// Attempts to convert the generic good `G` to a specific good `P::Good` with `filter_map`, which returns the original good if it cannot be converted, and converts the good recalled by `P` back to `G` with `unmap`.
+///
+/// A [`Recall`] never releases its fault and only converts it via [`From`] along with its good, so the fault thrown by `P` cannot be mapped with a closure. Instead, an insufficiency of `P` is thrown as [`FullStock`] and a defect of `P` as [`SpecificationDefect::Recalled`].
+pub struct FilterMapSpecifier<P, G, F, M, U> {
+    /// The actual producer.
+    producer: P,
+    /// Attempts to convert the generic good into the specific good.
+    filter_map: M,
+    /// Converts the specific good into the generic good.
+    unmap: U,
+    /// The type of the original good.
+    _good: PhantomData<G>,
+    /// The type of self's [`Flaws`].
+    _flaws: PhantomData<F>,
+}
+
+impl<P, G, F, M, U> FilterMapSpecifier<P, G, F, M, U> {
+    /// Creates a new [`FilterMapSpecifier`] that attempts to convert each good with `filter_map` and converts each recalled good with `unmap`.
+    pub const fn new(producer: P, filter_map: M, unmap: U) -> Self {
+        Self {
+            producer,
+            filter_map,
+            unmap,
+            _good: PhantomData,
+            _flaws: PhantomData,
+        }
+    }
+}
+
+impl<P, G, F, M, U> Agent for FilterMapSpecifier<P, G, F, M, U>
+where
+    P: Producer,
+{
+    type Good = G;
+}
+
+impl<P: Debug, G, F, M, U> Debug for FilterMapSpecifier<P, G, F, M, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FilterMapSpecifier {{ producer: {:?}, .. }}",
+            self.producer
+        )
+    }
+}
+
+impl<P, G, F, M, U> Display for FilterMapSpecifier<P, G, F, M, U>
+where
+    P: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Specifier for {}", self.producer)
+    }
+}
+
+impl<P, G, F, M, U> Producer for FilterMapSpecifier<P, G, F, M, U>
+where
+    P: Producer,
+    F: Flaws,
+    M: Fn(G) -> Result<P::Good, G>,
+    U: Fn(P::Good) -> G,
+    F::Insufficiency: From<FullStock>,
+{
+    type Flaws = SpecificationFlaws<F>;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        match (self.filter_map)(good) {
+            Ok(converted_good) => {
+                if let Err(recall) = self.producer.produce(converted_good) {
+                    let (specific_good, is_defect) = reclaim(recall);
+
+                    throw!(self.recall(specification_fault(is_defect), (self.unmap)(specific_good)));
+                }
+            }
+            Err(original_good) => throw!(self.recall(
+                Fault::Defect(SpecificationDefect::Unspecifiable),
+                original_good
+            )),
+        }
+    }
+}
+
+/// The [`Flaws`] of a [`Failure`] with the insufficiency `I` and the defect `D`.
+struct MappedFlaws<I, D> {
+    /// The type of the insufficiency.
+    _insufficiency: PhantomData<I>,
+    /// The type of the defect.
+    _defect: PhantomData<D>,
+}
+
+impl<I, D> Flaws for MappedFlaws<I, D> {
+    type Insufficiency = I;
+    type Defect = D;
+}
+
+/// Converts `failure` into a [`Failure`] with [`Flaws`] `W`, converting a defect with `map_defect` and an insufficiency into [`EmptyStock`].
+fn adapt_failure<F, W, D>(failure: Failure<F>, map_defect: D) -> Failure<W>
+where
+    F: Flaws,
+    W: Flaws,
+    W::Insufficiency: From<EmptyStock>,
+    D: Fn(F::Defect) -> W::Defect,
+{
+    let discarded: Failure<MappedFlaws<Discarded<F::Insufficiency>, F::Defect>> = failure.blame();
+    let emptied: Failure<MappedFlaws<EmptyStock, W::Defect>> = discarded
+        .map_defect::<_, MappedFlaws<_, W::Defect>>(map_defect)
+        .blame();
+
+    emptied.blame()
+}
+
+/// A [`Consumer`] that converts the good and defect with closures for the consumer `C`.
+///
+/// Converts the specific good `C::Good` into `G` with `map` and the defect of `C` into `F::Defect` with `map_defect`.
+///
+/// A [`Failure`] never releases its fault and only maps its defect with a closure, so an insufficiency of `C` is thrown as [`EmptyStock`].
+pub struct MapAdapter<C, G, F, M, D> {
+    /// The original consumer.
+    consumer: C,
+    /// Converts the specific good into the desired good.
+    map: M,
+    /// Converts the defect of `consumer` into the desired defect.
+    map_defect: D,
+    /// The desired type of good.
+    _good: PhantomData<G>,
+    /// The desired type of [`Flaws`].
+    _flaws: PhantomData<F>,
+}
+
+impl<C, G, F, M, D> MapAdapter<C, G, F, M, D> {
+    /// Creates a new [`MapAdapter`] that converts each good with `map` and each defect with `map_defect`.
+    pub const fn new(consumer: C, map: M, map_defect: D) -> Self {
+        Self {
+            consumer,
+            map,
+            map_defect,
+            _good: PhantomData,
+            _flaws: PhantomData,
+        }
+    }
+}
+
+impl<C, G, F, M, D> Agent for MapAdapter<C, G, F, M, D>
+where
+    C: Consumer,
+{
+    type Good = G;
+}
+
+impl<C, G, F, M, D> Consumer for MapAdapter<C, G, F, M, D>
+where
+    C: Consumer,
+    F: Flaws,
+    M: Fn(C::Good) -> G,
+    D: Fn(<C::Flaws as Flaws>::Defect) -> F::Defect,
+    F::Insufficiency: From<EmptyStock>,
+{
+    type Flaws = F;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        (self.map)(
+            self.consumer
+                .consume()
+                .map_err(|failure| adapt_failure(failure, &self.map_defect))?,
+        )
+    }
+}
+
+impl<C: Debug, G, F, M, D> Debug for MapAdapter<C, G, F, M, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "MapAdapter {{ consumer: {:?}, .. }}", self.consumer)
+    }
+}
+
+impl<C, G, F, M, D> Display for MapAdapter<C, G, F, M, D>
+where
+    C: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Adapter for {}", self.consumer)
+    }
+}
+
+/// A [`Consumer`] that converts the good with a closure that may discard the good, and converts the defect with a closure, for the consumer `C`.
+///
+/// Consumes from `C` until `filter_map` converts a specific good `C::Good` into `G`, discarding each good for which `filter_map` returns [`None`], and converts the defect of `C` into `F::Defect` with `map_defect`.
+///
+/// A [`Failure`] never releases its fault and only maps its defect with a closure, so an insufficiency of `C` is thrown as [`EmptyStock`].
+pub struct FilterMapAdapter<C, G, F, M, D> {
+    /// The original consumer.
+    consumer: C,
+    /// Attempts to convert the specific good into the desired good.
+    filter_map: M,
+    /// Converts the defect of `consumer` into the desired defect.
+    map_defect: D,
+    /// The desired type of good.
+    _good: PhantomData<G>,
+    /// The desired type of [`Flaws`].
+    _flaws: PhantomData<F>,
+}
+
+impl<C, G, F, M, D> FilterMapAdapter<C, G, F, M, D> {
+    /// Creates a new [`FilterMapAdapter`] that attempts to convert each good with `filter_map` and converts each defect with `map_defect`.
+    pub const fn new(consumer: C, filter_map: M, map_defect: D) -> Self {
+        Self {
+            consumer,
+            filter_map,
+            map_defect,
+            _good: PhantomData,
+            _flaws: PhantomData,
+        }
+    }
+}
+
+impl<C, G, F, M, D> Agent for FilterMapAdapter<C, G, F, M, D>
+where
+    C: Consumer,
+{
+    type Good = G;
+}
+
+impl<C, G, F, M, D> Consumer for FilterMapAdapter<C, G, F, M, D>
+where
+    C: Consumer,
+    F: Flaws,
+    M: Fn(C::Good) -> Option<G>,
+    D: Fn(<C::Flaws as Flaws>::Defect) -> F::Defect,
+    F::Insufficiency: From<EmptyStock>,
+{
+    type Flaws = F;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        loop {
+            let good = self
+                .consumer
+                .consume()
+                .map_err(|failure| adapt_failure(failure, &self.map_defect))?;
+
+            if let Some(converted_good) = (self.filter_map)(good) {
+                break converted_good;
+            }
+        }
+    }
+}
+
+impl<C: Debug, G, F, M, D> Debug for FilterMapAdapter<C, G, F, M, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FilterMapAdapter {{ consumer: {:?}, .. }}",
+            self.consumer
+        )
+    }
+}
+
+impl<C, G, F, M, D> Display for FilterMapAdapter<C, G, F, M, D>
+where
+    C: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Adapter for {}", self.consumer)
+    }
+}
@@ -0,0 +1,20 @@
+//! Defines the inspection of the goods stored in a market.
+
+/// Characterizes an agent that can report the state of its market without acting upon it.
+pub trait Inventory {
+    /// Returns the number of goods currently stored in the market.
+    fn len(&self) -> usize;
+
+    /// Returns if the market currently stores no goods.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of goods that the market can store, or [`None`] if the market has infinite capacity.
+    fn capacity(&self) -> Option<usize>;
+
+    /// Returns if every agent on the other side of the market has been dropped.
+    ///
+    /// A market that is acted upon by a single agent, such as a queue, is never withdrawn.
+    fn is_withdrawn(&self) -> bool;
+}
@@ -3,12 +3,36 @@
 #![no_std]
 
 extern crate alloc;
-#[cfg(feature = "std")]
+#[cfg(any(
+    feature = "std",
+    feature = "crossbeam-channel",
+    feature = "crossbeam-queue"
+))]
 extern crate std;
 
 pub mod compose;
 pub mod convert;
+pub mod inventory;
+pub mod queue_spsc;
+
+#[cfg(any(
+    feature = "std",
+    feature = "crossbeam-channel",
+    feature = "crossbeam-queue"
+))]
+mod clone_name;
+#[cfg(any(feature = "std", feature = "crossbeam-channel"))]
+mod presence;
+#[cfg(any(
+    feature = "std",
+    feature = "crossbeam-channel",
+    feature = "crossbeam-queue"
+))]
+mod sync;
 
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "std")))]
+pub mod broadcast;
 #[cfg(feature = "crossbeam-channel")]
 #[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "crossbeam-channel")))]
 pub mod channel_crossbeam;
@@ -27,6 +51,27 @@ pub mod process;
 #[cfg(feature = "crossbeam-queue")]
 #[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "crossbeam-queue")))]
 pub mod queue_crossbeam;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "std")))]
+pub mod queue_delay;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "std")))]
+pub mod queue_disk;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "std")))]
+pub mod queue_priority;
+#[cfg(any(feature = "std", feature = "crossbeam-channel"))]
+#[cfg_attr(
+    feature = "unstable-doc-cfg",
+    doc(cfg(any(feature = "std", feature = "crossbeam-channel")))
+)]
+pub mod select;
 #[cfg(feature = "thread")]
 #[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "thread")))]
 pub mod thread;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "std")))]
+pub mod time;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "unstable-doc-cfg", doc(cfg(feature = "std")))]
+pub mod watch;
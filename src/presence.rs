@@ -0,0 +1,90 @@
+//! Implements tracking of whether the agents on each side of a market are present.
+use {
+    crate::sync::Signal,
+    alloc::sync::{Arc, Weak},
+    core::sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Tracks the presence of the agents on one side of a market and of the agents on the other side.
+#[derive(Clone, Debug)]
+pub(crate) struct Presence {
+    /// Held by every agent on this side of the market.
+    ///
+    /// Only [`None`] while `self` is being dropped.
+    own: Option<Arc<()>>,
+    /// Refers to the item held by every agent on the other side of the market.
+    other: Weak<()>,
+    /// If this side of the market has been closed.
+    is_own_closed: Arc<AtomicBool>,
+    /// If the other side of the market has been closed.
+    is_other_closed: Arc<AtomicBool>,
+    /// Notified when either side of the market is closed or an agent is dropped.
+    signal: Arc<Signal>,
+}
+
+impl Presence {
+    /// Creates the [`Presence`]s for the two sides of a market.
+    pub(crate) fn pair() -> (Self, Self) {
+        let first = Arc::new(());
+        let second = Arc::new(());
+        let first_weak = Arc::downgrade(&first);
+        let second_weak = Arc::downgrade(&second);
+        let is_first_closed = Arc::new(AtomicBool::new(false));
+        let is_second_closed = Arc::new(AtomicBool::new(false));
+        let signal = Arc::new(Signal::default());
+
+        (
+            Self {
+                own: Some(first),
+                other: second_weak,
+                is_own_closed: Arc::clone(&is_first_closed),
+                is_other_closed: Arc::clone(&is_second_closed),
+                signal: Arc::clone(&signal),
+            },
+            Self {
+                own: Some(second),
+                other: first_weak,
+                is_own_closed: is_second_closed,
+                is_other_closed: is_first_closed,
+                signal,
+            },
+        )
+    }
+
+    /// Closes this side of the market.
+    pub(crate) fn close(&self) {
+        self.is_own_closed.store(true, Ordering::SeqCst);
+        self.signal.notify();
+    }
+
+    /// Returns if this side of the market has been closed.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.is_own_closed.load(Ordering::SeqCst)
+    }
+
+    /// Returns if every agent on the other side of the market has been dropped or the other side has been closed.
+    pub(crate) fn is_withdrawn(&self) -> bool {
+        self.other.strong_count() == 0 || self.is_other_closed.load(Ordering::SeqCst)
+    }
+
+    /// Returns if either side of the market has ended.
+    pub(crate) fn is_ended(&self) -> bool {
+        self.is_closed() || self.is_withdrawn()
+    }
+
+    /// Returns the [`Signal`] that is notified when the market changes.
+    ///
+    /// [`Presence`] notifies the [`Signal`] when a side is closed or an agent is dropped; the agents notify it when they change the stock of the market.
+    #[cfg(feature = "std")]
+    pub(crate) fn signal(&self) -> &Signal {
+        &self.signal
+    }
+}
+
+impl Drop for Presence {
+    fn drop(&mut self) {
+        // The item is dropped before notifying so that a woken agent observes the withdrawal.
+        drop(self.own.take());
+        self.signal.notify();
+    }
+}
@@ -1,22 +1,134 @@
 //! Implements [`Producer`]s and [`Consumer`]s for queues.
 use {
-    alloc::{string::String, sync::Arc},
-    core::fmt::{self, Display, Formatter},
+    crate::{clone_name::CloneName, inventory::Inventory, sync::Signal},
+    alloc::{sync::Arc, vec::Vec},
+    core::{
+        cmp,
+        fmt::{self, Display, Formatter},
+        time::Duration,
+    },
     crossbeam_queue::{ArrayQueue, SegQueue},
-    fehler::throws,
+    fehler::{throw, throws},
     market::{
         queue::{FiniteQueue, InfiniteQueue},
         Agent, Consumer, EmptyStock, Failure, Fault, Flawless, FullStock, Producer, Recall,
     },
+    std::time::Instant,
 };
 
 /// A [`InfiniteQueue`] implemented by [`crossbeam`].
+///
+/// Each clone is a handle to the same queue.
 #[derive(Debug)]
 pub struct CrossbeamInfiniteQueue<G> {
     /// The name of the queue.
-    name: String,
+    name: CloneName,
     /// The queue.
     queue: Arc<SegQueue<G>>,
+    /// Notified when a good is produced.
+    signal: Arc<Signal>,
+}
+
+impl<G> CrossbeamInfiniteQueue<G> {
+    /// Returns a handle to the queue that is only able to produce.
+    pub fn producer(&self) -> QueueProducer<Self> {
+        QueueProducer {
+            queue: self.clone(),
+        }
+    }
+
+    /// Returns a handle to the queue that is only able to consume.
+    pub fn consumer(&self) -> QueueConsumer<Self> {
+        QueueConsumer {
+            queue: self.clone(),
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available.
+    ///
+    /// Because [`SegQueue`] does not provide a blocking pop, the thread blocks on a signal that is notified by every production on the queue. So each production costs an additional fence and atomic load, and locks a mutex if a consumer is waiting.
+    pub fn consume_wait(&self) -> G {
+        loop {
+            // Without a deadline, pop_until() only returns once a good is retrieved.
+            if let Some(good) = self.pop_until(None) {
+                break good;
+            }
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws an [`EmptyStock`] insufficiency.
+    #[throws(Failure<EmptyStock>)]
+    pub fn consume_timeout(&self, timeout: Duration) -> G {
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            self.consume_deadline(deadline)?
+        } else {
+            self.consume_wait()
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available or `deadline` is reached.
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws an [`EmptyStock`] insufficiency.
+    #[throws(Failure<EmptyStock>)]
+    pub fn consume_deadline(&self, deadline: Instant) -> G {
+        if let Some(good) = self.pop_until(Some(deadline)) {
+            good
+        } else {
+            throw!(Consumer::failure(
+                self,
+                Fault::Insufficiency(EmptyStock::default())
+            ));
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available or `deadline`, if any, is reached.
+    fn pop_until(&self, deadline: Option<Instant>) -> Option<G> {
+        self.signal
+            .wait_until(deadline, (), |()| self.queue.pop().ok_or(()))
+            .ok()
+    }
+
+    /// Retrieves up to `limit` goods from the queue without blocking.
+    ///
+    /// The returned [`Vec`] is empty if the queue has no goods.
+    pub fn consume_batch(&self, limit: usize) -> Vec<G> {
+        let mut goods = Vec::with_capacity(cmp::min(limit, self.queue.len()));
+
+        while goods.len() < limit {
+            match self.queue.pop() {
+                Some(good) => goods.push(good),
+                None => break,
+            }
+        }
+
+        goods
+    }
+
+    /// Stores every good of `goods` in the queue.
+    pub fn produce_batch<I: IntoIterator<Item = G>>(&self, goods: I) {
+        for good in goods {
+            self.queue.push(good);
+        }
+
+        self.signal.notify();
+    }
+}
+
+impl<G> Clone for CrossbeamInfiniteQueue<G> {
+    /// Creates another handle to the same queue.
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            queue: Arc::clone(&self.queue),
+            signal: Arc::clone(&self.signal),
+        }
+    }
 }
 
 impl<G> Agent for CrossbeamInfiniteQueue<G> {
@@ -40,14 +152,29 @@ impl<G> Display for CrossbeamInfiniteQueue<G> {
     }
 }
 
+impl<G> Inventory for CrossbeamInfiniteQueue<G> {
+    fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        false
+    }
+}
+
 impl<G> InfiniteQueue<G> for CrossbeamInfiniteQueue<G> {
     fn allocate<S>(name_str: &S) -> Self
     where
         S: AsRef<str> + ?Sized,
     {
         Self {
-            name: String::from(name_str.as_ref()),
+            name: CloneName::new(name_str.as_ref()),
             queue: Arc::new(SegQueue::new()),
+            signal: Arc::default(),
         }
     }
 }
@@ -58,16 +185,134 @@ impl<G> Producer for CrossbeamInfiniteQueue<G> {
     #[throws(Recall<Self::Flaws, Self::Good>)]
     fn produce(&self, good: Self::Good) {
         self.queue.push(good);
+        self.signal.notify();
     }
 }
 
 /// A [`FiniteQueue`] implemented by [`crossbeam`].
+///
+/// Each clone is a handle to the same queue.
 #[derive(Debug)]
 pub struct CrossbeamFiniteQueue<G> {
     /// The name of the queue.
-    name: String,
+    name: CloneName,
     /// The queue.
     queue: Arc<ArrayQueue<G>>,
+    /// Notified when a good is produced.
+    signal: Arc<Signal>,
+}
+
+impl<G> CrossbeamFiniteQueue<G> {
+    /// Returns a handle to the queue that is only able to produce.
+    pub fn producer(&self) -> QueueProducer<Self> {
+        QueueProducer {
+            queue: self.clone(),
+        }
+    }
+
+    /// Returns a handle to the queue that is only able to consume.
+    pub fn consumer(&self) -> QueueConsumer<Self> {
+        QueueConsumer {
+            queue: self.clone(),
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available.
+    ///
+    /// Because [`ArrayQueue`] does not provide a blocking pop, the thread blocks on a signal that is notified by every production on the queue. So each production costs an additional fence and atomic load, and locks a mutex if a consumer is waiting.
+    pub fn consume_wait(&self) -> G {
+        loop {
+            // Without a deadline, pop_until() only returns once a good is retrieved.
+            if let Some(good) = self.pop_until(None) {
+                break good;
+            }
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws an [`EmptyStock`] insufficiency.
+    #[throws(Failure<EmptyStock>)]
+    pub fn consume_timeout(&self, timeout: Duration) -> G {
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            self.consume_deadline(deadline)?
+        } else {
+            self.consume_wait()
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available or `deadline` is reached.
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws an [`EmptyStock`] insufficiency.
+    #[throws(Failure<EmptyStock>)]
+    pub fn consume_deadline(&self, deadline: Instant) -> G {
+        if let Some(good) = self.pop_until(Some(deadline)) {
+            good
+        } else {
+            throw!(Consumer::failure(
+                self,
+                Fault::Insufficiency(EmptyStock::default())
+            ));
+        }
+    }
+
+    /// Retrieves the next good from the queue, blocking until one is available or `deadline`, if any, is reached.
+    fn pop_until(&self, deadline: Option<Instant>) -> Option<G> {
+        self.signal
+            .wait_until(deadline, (), |()| self.queue.pop().ok_or(()))
+            .ok()
+    }
+
+    /// Retrieves up to `limit` goods from the queue without blocking.
+    ///
+    /// The returned [`Vec`] is empty if the queue has no goods.
+    pub fn consume_batch(&self, limit: usize) -> Vec<G> {
+        let mut goods = Vec::with_capacity(cmp::min(limit, self.queue.len()));
+
+        while goods.len() < limit {
+            match self.queue.pop() {
+                Some(good) => goods.push(good),
+                None => break,
+            }
+        }
+
+        goods
+    }
+
+    /// Stores the goods of `goods` in the queue until it is full.
+    ///
+    /// Returns the goods that were not stored in their original order, which is empty if all goods were stored.
+    pub fn produce_batch<I: IntoIterator<Item = G>>(&self, goods: I) -> Vec<G> {
+        let mut goods = goods.into_iter();
+
+        let mut remainder = Vec::new();
+
+        while let Some(good) = goods.next() {
+            if let Err(rejected) = self.queue.push(good) {
+                remainder.push(rejected);
+                remainder.extend(goods);
+                break;
+            }
+        }
+
+        self.signal.notify();
+        remainder
+    }
+}
+
+impl<G> Clone for CrossbeamFiniteQueue<G> {
+    /// Creates another handle to the same queue.
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            queue: Arc::clone(&self.queue),
+            signal: Arc::clone(&self.signal),
+        }
+    }
 }
 
 impl<G> Agent for CrossbeamFiniteQueue<G> {
@@ -91,14 +336,29 @@ impl<G> Display for CrossbeamFiniteQueue<G> {
     }
 }
 
+impl<G> Inventory for CrossbeamFiniteQueue<G> {
+    fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(self.queue.capacity())
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        false
+    }
+}
+
 impl<G> FiniteQueue<G> for CrossbeamFiniteQueue<G> {
     fn allocate<S>(name_str: &S, size: usize) -> Self
     where
         S: AsRef<str> + ?Sized,
     {
         Self {
-            name: String::from(name_str.as_ref()),
+            name: CloneName::new(name_str.as_ref()),
             queue: Arc::new(ArrayQueue::new(size)),
+            signal: Arc::default(),
         }
     }
 }
@@ -110,6 +370,87 @@ impl<G> Producer for CrossbeamFiniteQueue<G> {
     fn produce(&self, good: Self::Good) {
         self.queue
             .push(good)
-            .map_err(|error| self.recall(Fault::Insufficiency(FullStock::default()), error))?
+            .map_err(|error| self.recall(Fault::Insufficiency(FullStock::default()), error))?;
+        self.signal.notify();
+    }
+}
+
+/// A handle to a queue of type `Q` that is only able to produce.
+#[derive(Clone, Debug)]
+pub struct QueueProducer<Q> {
+    /// The queue.
+    queue: Q,
+}
+
+impl<Q: Agent> Agent for QueueProducer<Q> {
+    type Good = Q::Good;
+}
+
+impl<Q: Display> Display for QueueProducer<Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.queue)
+    }
+}
+
+impl<Q: Inventory> Inventory for QueueProducer<Q> {
+    fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.queue.capacity()
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.queue.is_withdrawn()
+    }
+}
+
+impl<Q: Producer> Producer for QueueProducer<Q> {
+    type Flaws = Q::Flaws;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        self.queue.produce(good)?;
+    }
+}
+
+/// A handle to a queue of type `Q` that is only able to consume.
+#[derive(Clone, Debug)]
+pub struct QueueConsumer<Q> {
+    /// The queue.
+    queue: Q,
+}
+
+impl<Q: Agent> Agent for QueueConsumer<Q> {
+    type Good = Q::Good;
+}
+
+impl<Q: Consumer> Consumer for QueueConsumer<Q> {
+    type Flaws = Q::Flaws;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        self.queue.consume()?
+    }
+}
+
+impl<Q: Display> Display for QueueConsumer<Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.queue)
+    }
+}
+
+impl<Q: Inventory> Inventory for QueueConsumer<Q> {
+    fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.queue.capacity()
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        self.queue.is_withdrawn()
     }
 }
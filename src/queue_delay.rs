@@ -0,0 +1,196 @@
+//! Implements a queue that supplies goods only once they are ready.
+use {
+    crate::{inventory::Inventory, sync::lock},
+    alloc::{collections::BinaryHeap, string::String},
+    core::{
+        cmp::Ordering,
+        fmt::{self, Display, Formatter},
+        time::Duration,
+    },
+    fehler::{throw, throws},
+    market::{
+        queue::InfiniteQueue, Agent, Consumer, EmptyStock, Failure, Fault, Flawless, Producer,
+        Recall,
+    },
+    std::{sync::Mutex, time::Instant},
+};
+
+/// A good along with the [`Instant`] at which it is ready.
+#[derive(Debug)]
+struct Entry<G> {
+    /// The [`Instant`] at which the good is ready.
+    ready: Instant,
+    /// The number of goods produced before the good, which orders goods that are ready at the same [`Instant`].
+    sequence: u64,
+    /// The good.
+    good: G,
+}
+
+impl<G> Entry<G> {
+    /// Returns the key by which entries are ordered.
+    const fn key(&self) -> (Instant, u64) {
+        (self.ready, self.sequence)
+    }
+}
+
+impl<G> Eq for Entry<G> {}
+
+impl<G> Ord for Entry<G> {
+    /// Orders the entry that is ready first as the greatest so that it is at the top of a [`BinaryHeap`].
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key().cmp(&self.key())
+    }
+}
+
+impl<G> PartialEq for Entry<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<G> PartialOrd for Entry<G> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The goods stored by a [`DelayQueue`].
+#[derive(Debug)]
+struct Schedule<G> {
+    /// The goods, ordered by when they are ready.
+    entries: BinaryHeap<Entry<G>>,
+    /// The number of goods that have been produced.
+    sequence: u64,
+}
+
+/// An [`InfiniteQueue`] where each good is consumable only once its ready [`Instant`] has passed.
+///
+/// Goods are supplied in the order they become ready; goods that are ready at the same [`Instant`] are supplied in the order they were produced. Goods produced via [`Producer::produce`] are ready immediately.
+#[derive(Debug)]
+pub struct DelayQueue<G> {
+    /// The name of the queue.
+    name: String,
+    /// The goods.
+    schedule: Mutex<Schedule<G>>,
+}
+
+impl<G> DelayQueue<G> {
+    /// Stores `good` so that it is consumable once `ready` has passed.
+    pub fn produce_at(&self, good: G, ready: Instant) {
+        let mut schedule = lock(&self.schedule);
+        let sequence = schedule.sequence;
+
+        schedule.sequence = sequence.wrapping_add(1);
+        schedule.entries.push(Entry {
+            ready,
+            sequence,
+            good,
+        });
+    }
+
+    /// Stores `good` so that it is consumable once `delay` has elapsed from now.
+    ///
+    /// If `delay` is too large to be represented as an [`Instant`], `good` is ready at the furthest [`Instant`] that can be found by halving `delay`.
+    pub fn produce_after(&self, good: G, delay: Duration) {
+        let now = Instant::now();
+
+        self.produce_at(
+            good,
+            now.checked_add(delay).unwrap_or_else(|| {
+                let mut remaining = delay;
+
+                loop {
+                    remaining /= 2;
+
+                    if let Some(ready) = now.checked_add(remaining) {
+                        break ready;
+                    }
+                }
+            }),
+        );
+    }
+
+    /// Returns the [`Instant`] at which the next good is ready, or [`None`] if no goods are stored.
+    ///
+    /// This is intended for callers that want to sleep after [`Consumer::consume`] throws [`EmptyStock`].
+    pub fn next_ready(&self) -> Option<Instant> {
+        lock(&self.schedule).entries.peek().map(|entry| entry.ready)
+    }
+}
+
+impl<G> Agent for DelayQueue<G> {
+    type Good = G;
+}
+
+impl<G> Consumer for DelayQueue<G> {
+    type Flaws = EmptyStock;
+
+    /// Retrieves the good that became ready first.
+    ///
+    /// # Errors
+    ///
+    /// If no stored good is ready, throws [`EmptyStock`].
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let mut schedule = lock(&self.schedule);
+
+        match schedule.entries.peek() {
+            Some(entry) if entry.ready <= Instant::now() => {}
+            _ => throw!(Consumer::failure(
+                self,
+                Fault::Insufficiency(EmptyStock::default())
+            )),
+        }
+
+        schedule
+            .entries
+            .pop()
+            .map(|entry| entry.good)
+            .ok_or_else(|| Consumer::failure(self, Fault::Insufficiency(EmptyStock::default())))?
+    }
+}
+
+impl<G> Display for DelayQueue<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Inventory for DelayQueue<G> {
+    /// Returns the number of stored goods, including those that are not ready.
+    fn len(&self) -> usize {
+        lock(&self.schedule).entries.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        false
+    }
+}
+
+impl<G> InfiniteQueue<G> for DelayQueue<G> {
+    fn allocate<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            schedule: Mutex::new(Schedule {
+                entries: BinaryHeap::new(),
+                sequence: 0,
+            }),
+        }
+    }
+}
+
+impl<G> Producer for DelayQueue<G> {
+    type Flaws = Flawless;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        self.produce_at(good, Instant::now());
+    }
+}
@@ -0,0 +1,965 @@
+//! Implements a queue that stores its goods on disk so that they survive restarts.
+//!
+//! Goods are appended to a log of segment files in a directory. Consumed goods are only removed from the log once they are committed, so goods that were consumed but not committed are supplied again when the queue is reopened.
+//!
+//! Because writing to disk can fail, [`DiskQueue`] throws [`DiskDefect`]s instead of implementing [`InfiniteQueue`], which requires that production is flawless. [`DiskInfiniteQueue`] implements [`InfiniteQueue`] by producing the defects to a defect channel instead.
+use {
+    crate::{
+        channel_std::{StdFiniteChannel, StdReceiver, StdSyncSender},
+        inventory::Inventory,
+        sync::lock,
+    },
+    alloc::{
+        collections::VecDeque,
+        format,
+        string::{FromUtf8Error, String},
+        vec::Vec,
+    },
+    core::{
+        cmp,
+        convert::{Infallible, TryFrom},
+        fmt::{self, Debug, Display, Formatter},
+        marker::PhantomData,
+    },
+    fehler::{throw, throws},
+    market::{
+        channel::FiniteChannel, queue::InfiniteQueue, Agent, Consumer, ConsumptionFlaws,
+        EmptyStock, Failure, Fault, Flawless, Flaws, Producer, Recall,
+    },
+    std::{
+        fs::{self, File, OpenOptions},
+        io::{self, Read, Seek, SeekFrom, Write},
+        path::{Path, PathBuf},
+        sync::Mutex,
+    },
+};
+
+/// The number of bytes in the header of each record, which holds the length and checksum of the payload.
+const HEADER_LEN: usize = 8;
+/// The default maximum number of bytes in a segment.
+const DEFAULT_SEGMENT_SIZE: u64 = 16 * 1024 * 1024;
+/// The name of the file that stores the committed position.
+const COMMIT_FILE: &str = "commit";
+/// The name of the file used to atomically replace the commit file.
+const COMMIT_TEMP_FILE: &str = "commit.tmp";
+/// The extension of segment files.
+const SEGMENT_EXTENSION: &str = "segment";
+/// The number of defects that the defect channel of a [`DiskInfiniteQueue`] holds.
+const DEFECT_CAPACITY: usize = 64;
+
+/// Characterizes the conversion of goods to and from the bytes stored on disk.
+pub trait Codec<G> {
+    /// Specifies the error thrown when bytes cannot be decoded.
+    type Misstep;
+
+    /// Converts `good` into bytes.
+    fn encode(&self, good: &G) -> Vec<u8>;
+
+    /// Converts `bytes` into a good.
+    #[throws(Self::Misstep)]
+    fn decode(&self, bytes: Vec<u8>) -> G;
+}
+
+/// A [`Codec`] that stores bytes as they are.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BytesCodec;
+
+impl Codec<Vec<u8>> for BytesCodec {
+    type Misstep = Infallible;
+
+    fn encode(&self, good: &Vec<u8>) -> Vec<u8> {
+        good.clone()
+    }
+
+    #[throws(Self::Misstep)]
+    fn decode(&self, bytes: Vec<u8>) -> Vec<u8> {
+        bytes
+    }
+}
+
+/// A [`Codec`] that stores [`String`]s as UTF-8.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StringCodec;
+
+impl Codec<String> for StringCodec {
+    type Misstep = FromUtf8Error;
+
+    fn encode(&self, good: &String) -> Vec<u8> {
+        good.clone().into_bytes()
+    }
+
+    #[throws(Self::Misstep)]
+    fn decode(&self, bytes: Vec<u8>) -> String {
+        String::from_utf8(bytes)?
+    }
+}
+
+/// The defect thrown by a [`DiskQueue`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DiskDefect<M> {
+    /// An I/O operation failed.
+    Io(io::Error),
+    /// A stored good could not be decoded.
+    ///
+    /// The good is skipped so that the following goods can be consumed.
+    Decode(M),
+    /// A stored record was corrupted.
+    ///
+    /// The record is skipped so that the following goods can be consumed. If the length of the record was corrupted, the records that follow it in its segment cannot be located and are skipped as well.
+    Corrupt {
+        /// The index of the segment that holds the record.
+        segment: u64,
+        /// The number of bytes from the start of the segment to the record.
+        offset: u64,
+    },
+}
+
+impl<M: Display> Display for DiskDefect<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Io(ref error) => write!(f, "{}", error),
+            Self::Decode(ref misstep) => write!(f, "unable to decode good: {}", misstep),
+            Self::Corrupt { segment, offset } => write!(
+                f,
+                "corrupted record in segment {} at offset {}",
+                segment, offset
+            ),
+        }
+    }
+}
+
+impl<M: Debug + Display> std::error::Error for DiskDefect<M> {}
+
+impl<M> Flaws for DiskDefect<M> {
+    type Insufficiency = Flawless;
+    type Defect = Self;
+}
+
+impl<M> From<io::Error> for DiskDefect<M> {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Specifies when a [`DiskQueue`] flushes produced goods to the disk.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SyncPolicy {
+    /// Every good is flushed before [`Producer::produce`] returns.
+    EveryGood,
+    /// Goods are flushed once the given number of goods have been produced since the last flush.
+    EveryCount(usize),
+    /// Goods are only flushed by [`DiskQueue::sync`] or when the operating system decides.
+    Never,
+}
+
+#[allow(clippy::derivable_impls)] // Deriving Default for an enum requires a newer compiler.
+impl Default for SyncPolicy {
+    fn default() -> Self {
+        Self::EveryGood
+    }
+}
+
+/// A position in the log.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Position {
+    /// The index of the segment.
+    segment: u64,
+    /// The number of bytes from the start of the segment.
+    offset: u64,
+}
+
+impl Position {
+    /// Writes `self` to the commit file `file`.
+    #[throws(io::Error)]
+    fn write_to(self, file: &mut File) {
+        file.write_all(&self.segment.to_le_bytes())?;
+        file.write_all(&self.offset.to_le_bytes())?;
+    }
+
+    /// Reads a [`Position`] from the commit file `file`.
+    #[throws(io::Error)]
+    fn read_from(file: &mut File) -> Self {
+        let mut segment = [0; 8];
+        let mut offset = [0; 8];
+
+        file.read_exact(&mut segment)?;
+        file.read_exact(&mut offset)?;
+        Self {
+            segment: u64::from_le_bytes(segment),
+            offset: u64::from_le_bytes(offset),
+        }
+    }
+}
+
+/// The header of a record.
+#[derive(Clone, Copy, Debug)]
+struct Header {
+    /// The number of bytes in the payload.
+    len: u32,
+    /// The checksum of the payload.
+    sum: u32,
+}
+
+impl Header {
+    /// Converts `self` into the bytes stored in a segment.
+    fn to_bytes(self) -> [u8; HEADER_LEN] {
+        let [l0, l1, l2, l3] = self.len.to_le_bytes();
+        let [s0, s1, s2, s3] = self.sum.to_le_bytes();
+
+        [l0, l1, l2, l3, s0, s1, s2, s3]
+    }
+
+    /// Converts the bytes stored in a segment into a [`Header`].
+    fn from_bytes(bytes: [u8; HEADER_LEN]) -> Self {
+        let [l0, l1, l2, l3, s0, s1, s2, s3] = bytes;
+
+        Self {
+            len: u32::from_le_bytes([l0, l1, l2, l3]),
+            sum: u32::from_le_bytes([s0, s1, s2, s3]),
+        }
+    }
+}
+
+/// A record read from the log.
+enum Record {
+    /// The payload of a record whose checksum matches.
+    Intact(Vec<u8>),
+    /// The position of a record that was corrupted.
+    Corrupt(Position),
+}
+
+/// Returns the error thrown when a position in the log does not fit in its type.
+fn overflow() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "position in log exceeds the supported range",
+    )
+}
+
+/// Converts `value` into a [`u64`].
+#[throws(io::Error)]
+fn to_u64(value: usize) -> u64 {
+    u64::try_from(value).map_err(|_| overflow())?
+}
+
+/// Converts `value` into a [`usize`].
+#[throws(io::Error)]
+fn to_usize(value: u64) -> usize {
+    usize::try_from(value).map_err(|_| overflow())?
+}
+
+/// Returns the sum of `a` and `b`.
+#[throws(io::Error)]
+fn add(a: u64, b: u64) -> u64 {
+    a.checked_add(b).ok_or_else(overflow)?
+}
+
+/// Returns the FNV-1a checksum of `bytes`.
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Returns the end of the complete record that starts at `offset` in `bytes`, or [`None`] if the record is incomplete.
+///
+/// The checksum of the record is not verified, so a corrupted record is reported when it is read instead of hiding the records after it.
+fn record_end(bytes: &[u8], offset: usize) -> Option<usize> {
+    let payload_start = offset.checked_add(HEADER_LEN)?;
+    let header =
+        Header::from_bytes(<[u8; HEADER_LEN]>::try_from(bytes.get(offset..payload_start)?).ok()?);
+    let end = payload_start.checked_add(usize::try_from(header.len).ok()?)?;
+
+    if end <= bytes.len() {
+        Some(end)
+    } else {
+        None
+    }
+}
+
+/// Returns the number of complete records at the start of `bytes` along with the number of bytes they occupy.
+///
+/// Scanning stops at the first incomplete record, which is the result of a write that was interrupted.
+fn scan(bytes: &[u8]) -> (usize, usize) {
+    let mut count = 0_usize;
+    let mut offset = 0;
+
+    while let Some(end) = record_end(bytes, offset) {
+        count = count.saturating_add(1);
+        offset = end;
+    }
+
+    (count, offset)
+}
+
+/// Flushes the entries of `directory` to the disk so that files created or renamed in it survive a crash.
+#[cfg(unix)]
+#[throws(io::Error)]
+fn sync_directory(directory: &Path) {
+    File::open(directory)?.sync_all()?;
+}
+
+/// Does nothing because a directory can only be opened as a file on Unix.
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)] // Matches the signature used on Unix.
+fn sync_directory(_directory: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// The state of the log.
+#[derive(Debug)]
+struct Log {
+    /// The directory that holds the log.
+    directory: PathBuf,
+    /// The index of the oldest segment that has not been removed.
+    first_segment: u64,
+    /// The segment to which goods are appended.
+    writer: File,
+    /// The position at which the next good is appended.
+    write: Position,
+    /// The number of goods appended since the last flush.
+    unsynced: usize,
+    /// The segment from which goods are read, positioned at `read`.
+    reader: Option<File>,
+    /// The position of the next good to be consumed.
+    read: Position,
+    /// The position before which all goods have been committed.
+    committed: Position,
+    /// The number of goods that have not been consumed.
+    len: usize,
+}
+
+impl Log {
+    /// Returns the path of the segment at `index`.
+    fn segment_path(&self, index: u64) -> PathBuf {
+        segment_path(&self.directory, index)
+    }
+
+    /// Appends a record holding `payload`.
+    #[throws(io::Error)]
+    fn append(&mut self, payload: &[u8], segment_size: u64, policy: SyncPolicy) {
+        let len = u32::try_from(payload.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "good is too large"))?;
+        let record_len = add(to_u64(HEADER_LEN)?, u64::from(len))?;
+
+        if self.write.offset > 0 && add(self.write.offset, record_len)? > segment_size {
+            if policy != SyncPolicy::Never {
+                self.writer.sync_data()?;
+            }
+
+            let segment = add(self.write.segment, 1)?;
+            self.writer = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.segment_path(segment))?;
+            self.write = Position { segment, offset: 0 };
+
+            if policy != SyncPolicy::Never {
+                sync_directory(&self.directory)?;
+            }
+        }
+
+        let end = add(self.write.offset, record_len)?;
+        let mut record = Vec::with_capacity(HEADER_LEN.saturating_add(payload.len()));
+        record.extend_from_slice(
+            &Header {
+                len,
+                sum: checksum(payload),
+            }
+            .to_bytes(),
+        );
+        record.extend_from_slice(payload);
+
+        if let Err(error) = self.writer.write_all(&record) {
+            // Remove any partial record so that later records remain readable.
+            let _ = self.writer.set_len(self.write.offset);
+            throw!(error);
+        }
+
+        self.write.offset = end;
+        self.len = self.len.saturating_add(1);
+        self.unsynced = self.unsynced.saturating_add(1);
+
+        let is_sync_due = match policy {
+            SyncPolicy::EveryGood => true,
+            SyncPolicy::EveryCount(count) => self.unsynced >= count,
+            SyncPolicy::Never => false,
+        };
+
+        if is_sync_due {
+            self.sync()?;
+        }
+    }
+
+    /// Flushes all appended goods to the disk.
+    #[throws(io::Error)]
+    fn sync(&mut self) {
+        self.writer.sync_data()?;
+        self.unsynced = 0;
+    }
+
+    /// Reads the next record, or returns [`None`] if all records have been read.
+    ///
+    /// A corrupted record is skipped so that reading continues after it.
+    #[throws(io::Error)]
+    fn read_next(&mut self) -> Option<Record> {
+        self.skip_read_segments()?;
+
+        if self.read == self.write {
+            // Resynchronizes the count after records were skipped along with the rest of a segment.
+            self.len = 0;
+            None
+        } else {
+            let segment_end = if self.read.segment == self.write.segment {
+                self.write.offset
+            } else {
+                fs::metadata(self.segment_path(self.read.segment))?.len()
+            };
+            let record_start = self.read;
+            let payload_start = add(record_start.offset, to_u64(HEADER_LEN)?)?;
+            let mut reader = if let Some(reader) = self.reader.take() {
+                reader
+            } else {
+                let mut reader = File::open(self.segment_path(self.read.segment))?;
+                let _ = reader.seek(SeekFrom::Start(self.read.offset))?;
+                reader
+            };
+            let mut header = [0; HEADER_LEN];
+            let end = if payload_start > segment_end {
+                None
+            } else {
+                reader.read_exact(&mut header)?;
+                Some(add(
+                    payload_start,
+                    u64::from(Header::from_bytes(header).len),
+                )?)
+                .filter(|&end| end <= segment_end)
+            };
+
+            if let Some(record_end) = end {
+                let header = Header::from_bytes(header);
+                let mut payload = alloc::vec![0; to_usize(u64::from(header.len))?];
+
+                reader.read_exact(&mut payload)?;
+                self.read.offset = record_end;
+                self.len = self.len.saturating_sub(1);
+                self.reader = Some(reader);
+
+                if checksum(&payload) == header.sum {
+                    Some(Record::Intact(payload))
+                } else {
+                    Some(Record::Corrupt(record_start))
+                }
+            } else {
+                // The header is incomplete or its length is corrupted, so the following records of the segment cannot be located.
+                self.read.offset = segment_end;
+                self.len = self.len.saturating_sub(1);
+                Some(Record::Corrupt(record_start))
+            }
+        }
+    }
+
+    /// Moves the read position past every segment that has been completely read and is no longer appended.
+    #[throws(io::Error)]
+    fn skip_read_segments(&mut self) {
+        while self.read.segment < self.write.segment
+            && self.read.offset >= fs::metadata(self.segment_path(self.read.segment))?.len()
+        {
+            self.read = Position {
+                segment: add(self.read.segment, 1)?,
+                offset: 0,
+            };
+            self.reader = None;
+        }
+    }
+
+    /// Records that all read goods are committed and removes the segments that only hold committed goods.
+    #[throws(io::Error)]
+    fn commit(&mut self, policy: SyncPolicy) {
+        self.skip_read_segments()?;
+
+        let temp_path = self.directory.join(COMMIT_TEMP_FILE);
+        let mut file = File::create(&temp_path)?;
+
+        self.read.write_to(&mut file)?;
+
+        if policy != SyncPolicy::Never {
+            file.sync_data()?;
+        }
+
+        fs::rename(&temp_path, self.directory.join(COMMIT_FILE))?;
+
+        if policy != SyncPolicy::Never {
+            sync_directory(&self.directory)?;
+        }
+
+        self.committed = self.read;
+
+        while self.first_segment < self.committed.segment {
+            fs::remove_file(self.segment_path(self.first_segment))?;
+            self.first_segment = add(self.first_segment, 1)?;
+        }
+    }
+
+    /// Returns the number of goods between the committed and read positions.
+    #[throws(io::Error)]
+    fn count_uncommitted(&self) -> usize {
+        let mut count = 0_usize;
+
+        for segment in self.committed.segment..=self.read.segment {
+            let bytes = fs::read(self.segment_path(segment))?;
+            let start = if segment == self.committed.segment {
+                to_usize(self.committed.offset)?
+            } else {
+                0
+            };
+            let end = if segment == self.read.segment {
+                to_usize(self.read.offset)?
+            } else {
+                bytes.len()
+            };
+
+            count = count.saturating_add(scan(bytes.get(start..end).unwrap_or(&[])).0);
+        }
+
+        count
+    }
+}
+
+/// Returns the path of the segment at `index` in `directory`.
+fn segment_path(directory: &Path, index: u64) -> PathBuf {
+    directory.join(format!("{:020}.{}", index, SEGMENT_EXTENSION))
+}
+
+/// A queue of goods of type `G` stored on disk in the format defined by the [`Codec`] `C`.
+///
+/// Consuming a good only removes it from the queue in memory; [`DiskQueue::commit`] must be called to remove consumed goods from the disk.
+pub struct DiskQueue<G, C: Codec<G>> {
+    /// The name of the queue.
+    name: String,
+    /// The log.
+    log: Mutex<Log>,
+    /// Converts goods to and from bytes.
+    codec: C,
+    /// The maximum number of bytes in a segment.
+    segment_size: u64,
+    /// When goods are flushed to the disk.
+    policy: SyncPolicy,
+    /// The type of the good.
+    _good: PhantomData<fn(G) -> G>,
+}
+
+impl<G, C: Codec<G>> DiskQueue<G, C> {
+    /// Opens the queue stored in `directory`, creating it if it does not exist.
+    ///
+    /// The queue supplies every good that has not been committed. Any record left incomplete by an interrupted write is removed, while a corrupted record is kept so that consuming it throws [`DiskDefect::Corrupt`].
+    ///
+    /// # Errors
+    ///
+    /// If an I/O operation fails, throws the [`io::Error`].
+    #[throws(io::Error)]
+    pub fn open<S, P>(name_str: &S, directory: P, codec: C) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        P: AsRef<Path>,
+    {
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory)?;
+
+        let committed = match File::open(directory.join(COMMIT_FILE)) {
+            Ok(mut file) => Position::read_from(&mut file)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Position {
+                segment: 0,
+                offset: 0,
+            },
+            Err(error) => throw!(error),
+        };
+
+        let mut segments = Vec::new();
+
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+
+            if path.extension().and_then(|extension| extension.to_str()) == Some(SEGMENT_EXTENSION)
+            {
+                if let Some(index) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.parse::<u64>().ok())
+                {
+                    if index < committed.segment {
+                        fs::remove_file(&path)?;
+                    } else {
+                        segments.push(index);
+                    }
+                }
+            }
+        }
+
+        segments.sort_unstable();
+
+        let last_segment = segments.last().copied().unwrap_or(committed.segment);
+        let mut len = 0_usize;
+        let mut write_offset = 0;
+
+        for &segment in &segments {
+            let bytes = fs::read(segment_path(&directory, segment))?;
+            let start = if segment == committed.segment {
+                usize::try_from(committed.offset)
+                    .map_or(bytes.len(), |offset| cmp::min(offset, bytes.len()))
+            } else {
+                0
+            };
+            let (count, valid_len) = scan(bytes.get(start..).unwrap_or(&[]));
+
+            len = len.saturating_add(count);
+
+            if segment == last_segment {
+                write_offset = add(to_u64(start)?, to_u64(valid_len)?)?;
+            }
+        }
+
+        let writer = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(segment_path(&directory, last_segment))?;
+
+        // Remove the remains of an interrupted write.
+        writer.set_len(write_offset)?;
+
+        Self {
+            name: String::from(name_str.as_ref()),
+            log: Mutex::new(Log {
+                directory,
+                first_segment: segments.first().copied().unwrap_or(last_segment),
+                writer,
+                write: Position {
+                    segment: last_segment,
+                    offset: write_offset,
+                },
+                unsynced: 0,
+                reader: None,
+                read: committed,
+                committed,
+                len,
+            }),
+            codec,
+            segment_size: DEFAULT_SEGMENT_SIZE,
+            policy: SyncPolicy::default(),
+            _good: PhantomData,
+        }
+    }
+
+    /// Sets when produced goods are flushed to the disk.
+    #[must_use]
+    pub fn with_sync_policy(mut self, policy: SyncPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets the number of bytes after which goods are appended to a new segment.
+    #[must_use]
+    pub fn with_segment_size(mut self, segment_size: u64) -> Self {
+        self.segment_size = segment_size;
+        self
+    }
+
+    /// Removes all consumed goods from the disk.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O operation fails, throws the [`io::Error`].
+    #[throws(io::Error)]
+    pub fn commit(&self) {
+        lock(&self.log).commit(self.policy)?;
+    }
+
+    /// Supplies all consumed goods that have not been committed again.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O operation fails, throws the [`io::Error`].
+    #[throws(io::Error)]
+    pub fn rollback(&self) {
+        let mut log = lock(&self.log);
+        let uncommitted = log.count_uncommitted()?;
+
+        log.len = log.len.saturating_add(uncommitted);
+        log.read = log.committed;
+        log.reader = None;
+    }
+
+    /// Flushes all produced goods to the disk.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O operation fails, throws the [`io::Error`].
+    #[throws(io::Error)]
+    pub fn sync(&self) {
+        lock(&self.log).sync()?;
+    }
+
+    /// Retrieves the oldest good that has not been consumed, or [`None`] if all goods have been consumed.
+    #[throws(DiskDefect<C::Misstep>)]
+    fn take(&self) -> Option<G> {
+        let record = lock(&self.log).read_next()?;
+
+        match record {
+            Some(Record::Intact(bytes)) => {
+                Some(self.codec.decode(bytes).map_err(DiskDefect::Decode)?)
+            }
+            Some(Record::Corrupt(position)) => throw!(DiskDefect::Corrupt {
+                segment: position.segment,
+                offset: position.offset,
+            }),
+            None => None,
+        }
+    }
+
+    /// Appends `good` to the log.
+    #[throws(io::Error)]
+    fn append(&self, good: &G) {
+        let payload = self.codec.encode(good);
+
+        lock(&self.log).append(&payload, self.segment_size, self.policy)?;
+    }
+}
+
+impl<G, C: Codec<G>> Agent for DiskQueue<G, C> {
+    type Good = G;
+}
+
+impl<G, C: Codec<G>> Consumer for DiskQueue<G, C> {
+    type Flaws = ConsumptionFlaws<DiskDefect<C::Misstep>>;
+
+    /// Retrieves the oldest good that has not been consumed.
+    ///
+    /// # Errors
+    ///
+    /// If all goods have been consumed, throws [`EmptyStock`]. If reading from the disk fails, throws [`DiskDefect::Io`]. If the good cannot be decoded, throws [`DiskDefect::Decode`]. If the record of the good is corrupted, throws [`DiskDefect::Corrupt`].
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        self.take()
+            .map_err(|defect| self.failure(Fault::Defect(defect)))?
+            .ok_or_else(|| self.failure(Fault::Insufficiency(EmptyStock::default())))?
+    }
+}
+
+impl<G, C: Codec<G>> Debug for DiskQueue<G, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DiskQueue {{ name: {:?}, segment_size: {}, policy: {:?}, .. }}",
+            self.name, self.segment_size, self.policy
+        )
+    }
+}
+
+impl<G, C: Codec<G>> Display for DiskQueue<G, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G, C: Codec<G>> Inventory for DiskQueue<G, C> {
+    /// Returns the number of goods that have not been consumed.
+    fn len(&self) -> usize {
+        lock(&self.log).len
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        false
+    }
+}
+
+impl<G, C: Codec<G>> Producer for DiskQueue<G, C> {
+    type Flaws = DiskDefect<C::Misstep>;
+
+    /// Appends `good` to the log, flushing it to the disk according to the [`SyncPolicy`] of `self`.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the disk fails, throws [`DiskDefect::Io`].
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        if let Err(error) = self.append(&good) {
+            throw!(self.recall(Fault::Defect(error.into()), good));
+        }
+    }
+}
+
+/// An [`InfiniteQueue`] that stores its goods in a [`DiskQueue`].
+///
+/// Instead of being thrown, the defects of the [`DiskQueue`] are produced to a defect channel whose [`Consumer`] is retrieved by [`DiskInfiniteQueue::defect_consumer()`]. The channel holds up to 64 defects; later defects are discarded until the held defects are consumed.
+///
+/// A good that cannot be written to the disk is held in memory, where it does not survive a restart, and is supplied once the goods on the disk have been consumed. A good that cannot be decoded or whose record is corrupted is skipped.
+pub struct DiskInfiniteQueue<G, C: Codec<G>> {
+    /// The name of the queue.
+    name: String,
+    /// The queue that stores the goods, or [`None`] if it could not be opened.
+    disk: Option<DiskQueue<G, C>>,
+    /// The goods that could not be written to the disk.
+    spilled: Mutex<VecDeque<G>>,
+    /// Produces the defects of `disk`.
+    defect_producer: Mutex<StdSyncSender<DiskDefect<C::Misstep>>>,
+    /// Consumes the defects of `disk` until it is retrieved.
+    defect_consumer: Mutex<Option<StdReceiver<DiskDefect<C::Misstep>>>>,
+}
+
+impl<G, C: Codec<G>> DiskInfiniteQueue<G, C> {
+    /// Creates a [`DiskInfiniteQueue`] that stores its goods in `disk`.
+    fn new<S>(name_str: &S, disk: Option<DiskQueue<G, C>>) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        let (defect_producer, defect_consumer) =
+            StdFiniteChannel::establish(&format!("{} defects", name_str.as_ref()), DEFECT_CAPACITY);
+
+        Self {
+            name: String::from(name_str.as_ref()),
+            disk,
+            spilled: Mutex::new(VecDeque::new()),
+            defect_producer: Mutex::new(defect_producer),
+            defect_consumer: Mutex::new(Some(defect_consumer)),
+        }
+    }
+
+    /// Returns the [`DiskQueue`] that stores the goods, or [`None`] if it could not be opened.
+    ///
+    /// This is intended for committing, rolling back and syncing the stored goods.
+    pub fn disk(&self) -> Option<&DiskQueue<G, C>> {
+        self.disk.as_ref()
+    }
+
+    /// Returns the [`Consumer`] of the defects thrown while storing and retrieving goods.
+    ///
+    /// Only the first call returns the [`Consumer`]; later calls return [`None`].
+    pub fn defect_consumer(&self) -> Option<StdReceiver<DiskDefect<C::Misstep>>> {
+        lock(&self.defect_consumer).take()
+    }
+
+    /// Produces `defect` to the defect channel, discarding it if the channel is full or its [`Consumer`] has been dropped.
+    fn report(&self, defect: DiskDefect<C::Misstep>) {
+        let _ = lock(&self.defect_producer).produce(defect);
+    }
+}
+
+impl<G, C: Codec<G>> Agent for DiskInfiniteQueue<G, C> {
+    type Good = G;
+}
+
+impl<G, C: Codec<G>> Consumer for DiskInfiniteQueue<G, C> {
+    type Flaws = EmptyStock;
+
+    /// Retrieves the oldest good on the disk, or the oldest good held in memory if no good on the disk can be retrieved.
+    ///
+    /// # Errors
+    ///
+    /// If no good is stored, throws [`EmptyStock`].
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let stored = self.disk.as_ref().and_then(|disk| loop {
+            match disk.take() {
+                Ok(good) => break good,
+                Err(defect) => {
+                    // Only a failed read leaves the good on the disk; any other defect skips it.
+                    let is_stuck = matches!(defect, DiskDefect::Io(_));
+
+                    self.report(defect);
+
+                    if is_stuck {
+                        break None;
+                    }
+                }
+            }
+        });
+
+        stored
+            .or_else(|| lock(&self.spilled).pop_front())
+            .ok_or_else(|| self.failure(Fault::Insufficiency(EmptyStock::default())))?
+    }
+}
+
+impl<G, C: Codec<G>> Debug for DiskInfiniteQueue<G, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DiskInfiniteQueue {{ name: {:?}, disk: {:?}, .. }}",
+            self.name, self.disk
+        )
+    }
+}
+
+impl<G, C: Codec<G>> Display for DiskInfiniteQueue<G, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G, C: Codec<G>> From<DiskQueue<G, C>> for DiskInfiniteQueue<G, C> {
+    fn from(disk: DiskQueue<G, C>) -> Self {
+        let name = disk.name.clone();
+
+        Self::new(&name, Some(disk))
+    }
+}
+
+impl<G, C: Codec<G> + Default> InfiniteQueue<G> for DiskInfiniteQueue<G, C> {
+    /// Opens the queue stored in the directory at the path `name_str` with the default [`Codec`].
+    ///
+    /// If the queue cannot be opened, the [`io::Error`] is produced to the defect channel and goods are only held in memory.
+    fn allocate<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        match DiskQueue::open(name_str, name_str.as_ref(), C::default()) {
+            Ok(disk) => Self::new(name_str, Some(disk)),
+            Err(error) => {
+                let queue = Self::new(name_str, None);
+
+                queue.report(DiskDefect::Io(error));
+                queue
+            }
+        }
+    }
+}
+
+impl<G, C: Codec<G>> Inventory for DiskInfiniteQueue<G, C> {
+    /// Returns the number of goods that have not been consumed, including those held in memory.
+    fn len(&self) -> usize {
+        self.disk
+            .as_ref()
+            .map_or(0, Inventory::len)
+            .saturating_add(lock(&self.spilled).len())
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        false
+    }
+}
+
+impl<G, C: Codec<G>> Producer for DiskInfiniteQueue<G, C> {
+    type Flaws = Flawless;
+
+    /// Appends `good` to the disk, holding it in memory if it cannot be written.
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        match self.disk.as_ref().map(|disk| disk.append(&good)) {
+            Some(Ok(())) => {}
+            Some(Err(error)) => {
+                self.report(DiskDefect::Io(error));
+                lock(&self.spilled).push_back(good);
+            }
+            None => lock(&self.spilled).push_back(good),
+        }
+    }
+}
@@ -0,0 +1,375 @@
+//! Implements queues that supply the good with the highest priority first.
+use {
+    crate::{inventory::Inventory, sync::lock},
+    alloc::{boxed::Box, collections::BinaryHeap, string::String, sync::Arc},
+    core::{
+        cmp::Ordering,
+        fmt::{self, Debug, Display, Formatter},
+        mem,
+    },
+    fehler::{throw, throws},
+    market::{
+        queue::{FiniteQueue, InfiniteQueue},
+        Agent, Consumer, EmptyStock, Failure, Fault, Flawless, FullStock, Producer, Recall,
+    },
+    std::sync::Mutex,
+};
+
+/// Compares the priorities of 2 goods by the results of a key closure.
+type KeyOrder<G> = Box<dyn Fn(&G, &G) -> Ordering + Send + Sync>;
+
+/// Compares the priorities of 2 goods.
+enum Order<G> {
+    /// Compares the goods by [`Ord`].
+    Natural(fn(&G, &G) -> Ordering),
+    /// Compares the results of a key closure.
+    Key(KeyOrder<G>),
+}
+
+impl<G> Order<G> {
+    /// Compares the priorities of `a` and `b`.
+    fn compare(&self, a: &G, b: &G) -> Ordering {
+        match *self {
+            Self::Natural(ref order) => order(a, b),
+            Self::Key(ref order) => order(a, b),
+        }
+    }
+}
+
+/// A good along with the number of goods produced before it.
+struct Entry<G> {
+    /// The good.
+    good: G,
+    /// The number of goods produced before the good, which orders goods with equal priority.
+    sequence: u64,
+    /// Compares the priorities of goods.
+    order: Arc<Order<G>>,
+}
+
+impl<G> Eq for Entry<G> {}
+
+impl<G> Ord for Entry<G> {
+    /// Orders the entry with the highest priority as the greatest so that it is at the top of a [`BinaryHeap`]; of entries with equal priority, the oldest is the greatest.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.order
+            .compare(&self.good, &other.good)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<G> PartialEq for Entry<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<G> PartialOrd for Entry<G> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The goods stored by a [`Ranking`].
+struct Shelf<G> {
+    /// The goods, ordered by priority.
+    entries: BinaryHeap<Entry<G>>,
+    /// The number of goods that have been produced.
+    sequence: u64,
+}
+
+/// Goods ordered by priority.
+struct Ranking<G> {
+    /// The goods.
+    shelf: Mutex<Shelf<G>>,
+    /// Compares the priorities of goods.
+    order: Arc<Order<G>>,
+}
+
+impl<G> Ranking<G> {
+    /// Creates an empty [`Ranking`] that compares goods with `order`.
+    fn new(order: Order<G>) -> Self {
+        Self {
+            shelf: Mutex::new(Shelf {
+                entries: BinaryHeap::new(),
+                sequence: 0,
+            }),
+            order: Arc::new(order),
+        }
+    }
+
+    /// Inserts `good` into `shelf`.
+    fn insert(&self, shelf: &mut Shelf<G>, good: G) {
+        let sequence = shelf.sequence;
+
+        shelf.sequence = sequence.wrapping_add(1);
+        shelf.entries.push(Entry {
+            good,
+            sequence,
+            order: Arc::clone(&self.order),
+        });
+    }
+
+    /// Removes the good with the lowest priority from `shelf` if its priority is lower than that of `good`.
+    ///
+    /// Returns if a good was removed. Finding the good with the lowest priority visits every stored good.
+    fn evict_lower(&self, shelf: &mut Shelf<G>, good: &G) -> bool {
+        let mut entries = mem::take(&mut shelf.entries).into_vec();
+        let lowest = entries
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.cmp(b.1))
+            .filter(|&(_, entry)| self.order.compare(&entry.good, good) == Ordering::Less)
+            .map(|(index, _)| index);
+
+        if let Some(index) = lowest {
+            drop(entries.swap_remove(index));
+        }
+
+        shelf.entries = BinaryHeap::from(entries);
+        lowest.is_some()
+    }
+
+    /// Removes the good with the highest priority.
+    fn pop(&self) -> Option<G> {
+        lock(&self.shelf).entries.pop().map(|entry| entry.good)
+    }
+
+    /// Returns the number of goods.
+    fn len(&self) -> usize {
+        lock(&self.shelf).entries.len()
+    }
+}
+
+/// Returns the [`Order`] of `key`.
+fn key_order<G, K, F>(key: F) -> Order<G>
+where
+    K: Ord,
+    F: Fn(&G) -> K + Send + Sync + 'static,
+{
+    Order::Key(Box::new(move |a, b| key(a).cmp(&key(b))))
+}
+
+/// An [`InfiniteQueue`] that supplies the good with the highest priority first.
+///
+/// Goods with equal priority are supplied in the order they were produced.
+pub struct PriorityInfiniteQueue<G> {
+    /// The name of the queue.
+    name: String,
+    /// The goods.
+    ranking: Ranking<G>,
+}
+
+impl<G> PriorityInfiniteQueue<G> {
+    /// Creates an empty queue where the priority of a good is the result of `key`.
+    pub fn with_key<S, K, F>(name_str: &S, key: F) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        K: Ord,
+        F: Fn(&G) -> K + Send + Sync + 'static,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            ranking: Ranking::new(key_order(key)),
+        }
+    }
+}
+
+impl<G> Agent for PriorityInfiniteQueue<G> {
+    type Good = G;
+}
+
+impl<G> Consumer for PriorityInfiniteQueue<G> {
+    type Flaws = EmptyStock;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        self.ranking
+            .pop()
+            .ok_or_else(|| Consumer::failure(self, Fault::Insufficiency(EmptyStock::default())))?
+    }
+}
+
+impl<G> Debug for PriorityInfiniteQueue<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "PriorityInfiniteQueue {{ name: {:?}, .. }}", self.name)
+    }
+}
+
+impl<G> Display for PriorityInfiniteQueue<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Inventory for PriorityInfiniteQueue<G> {
+    fn len(&self) -> usize {
+        self.ranking.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        false
+    }
+}
+
+impl<G: Ord> InfiniteQueue<G> for PriorityInfiniteQueue<G> {
+    fn allocate<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            ranking: Ranking::new(Order::Natural(G::cmp)),
+        }
+    }
+}
+
+impl<G> Producer for PriorityInfiniteQueue<G> {
+    type Flaws = Flawless;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        self.ranking.insert(&mut lock(&self.ranking.shelf), good);
+    }
+}
+
+/// Specifies how a [`PriorityFiniteQueue`] handles a good produced while it is full.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Overflow {
+    /// The good is recalled with [`FullStock`].
+    Reject,
+    /// The good with the lowest priority is evicted if its priority is lower than that of the produced good; otherwise the produced good is recalled with [`FullStock`].
+    ///
+    /// Finding the good with the lowest priority visits every stored good, so a produce that evicts takes linear time.
+    EvictLowest,
+}
+
+#[allow(clippy::derivable_impls)] // Deriving Default for an enum requires a newer compiler.
+impl Default for Overflow {
+    fn default() -> Self {
+        Self::Reject
+    }
+}
+
+/// A [`FiniteQueue`] that supplies the good with the highest priority first.
+///
+/// Goods with equal priority are supplied in the order they were produced.
+pub struct PriorityFiniteQueue<G> {
+    /// The name of the queue.
+    name: String,
+    /// The goods.
+    ranking: Ranking<G>,
+    /// The maximum number of goods.
+    size: usize,
+    /// How a good produced while the queue is full is handled.
+    overflow: Overflow,
+}
+
+impl<G> PriorityFiniteQueue<G> {
+    /// Creates an empty queue that holds up to `size` goods where the priority of a good is the result of `key`.
+    pub fn with_key<S, K, F>(name_str: &S, size: usize, key: F) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+        K: Ord,
+        F: Fn(&G) -> K + Send + Sync + 'static,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            ranking: Ranking::new(key_order(key)),
+            size,
+            overflow: Overflow::default(),
+        }
+    }
+
+    /// Sets how a good produced while `self` is full is handled.
+    #[must_use]
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+impl<G> Agent for PriorityFiniteQueue<G> {
+    type Good = G;
+}
+
+impl<G> Consumer for PriorityFiniteQueue<G> {
+    type Flaws = EmptyStock;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        self.ranking
+            .pop()
+            .ok_or_else(|| Consumer::failure(self, Fault::Insufficiency(EmptyStock::default())))?
+    }
+}
+
+impl<G> Debug for PriorityFiniteQueue<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PriorityFiniteQueue {{ name: {:?}, size: {}, overflow: {:?}, .. }}",
+            self.name, self.size, self.overflow
+        )
+    }
+}
+
+impl<G> Display for PriorityFiniteQueue<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Inventory for PriorityFiniteQueue<G> {
+    fn len(&self) -> usize {
+        self.ranking.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(self.size)
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        false
+    }
+}
+
+impl<G: Ord> FiniteQueue<G> for PriorityFiniteQueue<G> {
+    fn allocate<S>(name_str: &S, size: usize) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            ranking: Ranking::new(Order::Natural(G::cmp)),
+            size,
+            overflow: Overflow::default(),
+        }
+    }
+}
+
+impl<G> Producer for PriorityFiniteQueue<G> {
+    type Flaws = FullStock;
+
+    /// Inserts `good` into the queue.
+    ///
+    /// # Errors
+    ///
+    /// If the queue is full and `good` cannot replace a good according to the [`Overflow`] of `self`, throws [`FullStock`].
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        let mut shelf = lock(&self.ranking.shelf);
+
+        if shelf.entries.len() >= self.size
+            && !(self.overflow == Overflow::EvictLowest
+                && self.ranking.evict_lower(&mut shelf, &good))
+        {
+            throw!(self.recall(Fault::Insufficiency(FullStock::default()), good));
+        }
+
+        self.ranking.insert(&mut shelf, good);
+    }
+}
@@ -0,0 +1,298 @@
+//! Implements a bounded queue with a single [`Producer`] and a single [`Consumer`] that does not lock.
+use {
+    crate::inventory::Inventory,
+    alloc::{boxed::Box, string::String, sync::Arc, vec::Vec},
+    core::{
+        cell::{Cell, UnsafeCell},
+        fmt::{self, Debug, Display, Formatter},
+        marker::PhantomData,
+        mem::MaybeUninit,
+        sync::atomic::{AtomicUsize, Ordering},
+    },
+    fehler::{throw, throws},
+    market::{Agent, Consumer, EmptyStock, Failure, Fault, FullStock, Producer, Recall},
+};
+
+/// An [`AtomicUsize`] aligned to its own cache line so that the producer and consumer do not contend over the same line.
+#[derive(Debug)]
+#[repr(align(64))]
+struct Counter(AtomicUsize);
+
+/// The buffer shared by a [`SpscProducer`] and a [`SpscConsumer`].
+struct Ring<G> {
+    /// The slots that store goods.
+    ///
+    /// The number of slots is a power of 2 so that the slot of a count stays in order when the count wraps. The slots from `head` to `tail` (modulo the number of slots) are initialized.
+    slots: Box<[UnsafeCell<MaybeUninit<G>>]>,
+    /// The maximum number of goods stored in `slots`.
+    capacity: usize,
+    /// The number of goods that have been consumed, which is only modified by the consumer.
+    head: Counter,
+    /// The number of goods that have been produced, which is only modified by the producer.
+    tail: Counter,
+}
+
+impl<G> Ring<G> {
+    /// Creates a [`Ring`] that stores up to `size` goods.
+    fn new(size: usize) -> Self {
+        // A size too large for a power of 2 cannot be allocated anyway.
+        let slot_count = size.checked_next_power_of_two().unwrap_or(usize::MAX);
+
+        Self {
+            capacity: size,
+            slots: (0..slot_count)
+                .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+            head: Counter(AtomicUsize::new(0)),
+            tail: Counter(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the slot that stores the good with `count`.
+    fn slot(&self, count: usize) -> &UnsafeCell<MaybeUninit<G>> {
+        #[allow(clippy::indexing_slicing)]
+        // There is always at least 1 slot and the number of slots is a power of 2, so the mask is within the slots.
+        &self.slots[count & (self.slots.len() - 1)]
+    }
+
+    /// Returns the number of goods stored in `self`.
+    fn len(&self) -> usize {
+        let head = self.head.0.load(Ordering::Acquire);
+        self.tail.0.load(Ordering::Acquire).wrapping_sub(head)
+    }
+}
+
+#[allow(unsafe_code)] // Goods are only accessed by the single producer or the single consumer, as synchronized by `head` and `tail`.
+unsafe impl<G: Send> Send for Ring<G> {}
+
+#[allow(unsafe_code)] // Goods are only accessed by the single producer or the single consumer, as synchronized by `head` and `tail`.
+unsafe impl<G: Send> Sync for Ring<G> {}
+
+impl<G> Drop for Ring<G> {
+    fn drop(&mut self) {
+        let tail = *self.tail.0.get_mut();
+        let mut head = *self.head.0.get_mut();
+
+        while head != tail {
+            #[allow(unsafe_code)]
+            // The slots from head to tail are initialized and are no longer accessible by either half.
+            unsafe {
+                (*self.slot(head).get()).as_mut_ptr().drop_in_place();
+            }
+
+            head = head.wrapping_add(1);
+        }
+    }
+}
+
+/// The [`Producer`] of a [`SpscQueue`].
+///
+/// The producer can be sent to another thread but cannot be cloned or shared, which guarantees that there is a single producer.
+pub struct SpscProducer<G> {
+    /// The name of the queue.
+    name: String,
+    /// The buffer.
+    ring: Arc<Ring<G>>,
+    /// The last known number of goods that have been consumed.
+    ///
+    /// Being a [`Cell`], this also prevents `self` from being shared between threads.
+    head: Cell<usize>,
+}
+
+impl<G> Agent for SpscProducer<G> {
+    type Good = G;
+}
+
+impl<G> Debug for SpscProducer<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "SpscProducer {{ name: {:?}, .. }}", self.name)
+    }
+}
+
+impl<G> Display for SpscProducer<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Inventory for SpscProducer<G> {
+    fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(self.ring.capacity)
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        Arc::strong_count(&self.ring) == 1
+    }
+}
+
+impl<G> Producer for SpscProducer<G> {
+    type Flaws = FullStock;
+
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        let size = self.ring.capacity;
+        let tail = self.ring.tail.0.load(Ordering::Relaxed);
+
+        if tail.wrapping_sub(self.head.get()) >= size {
+            self.head.set(self.ring.head.0.load(Ordering::Acquire));
+
+            if tail.wrapping_sub(self.head.get()) >= size {
+                throw!(self.recall(Fault::Insufficiency(FullStock::default()), good));
+            }
+        }
+
+        #[allow(unsafe_code)]
+        // The slot at tail is not initialized and is only accessible by the producer until tail is incremented.
+        unsafe {
+            (*self.ring.slot(tail).get()).as_mut_ptr().write(good);
+        }
+
+        self.ring
+            .tail
+            .0
+            .store(tail.wrapping_add(1), Ordering::Release);
+    }
+}
+
+/// The [`Consumer`] of a [`SpscQueue`].
+///
+/// The consumer can be sent to another thread but cannot be cloned or shared, which guarantees that there is a single consumer.
+pub struct SpscConsumer<G> {
+    /// The name of the queue.
+    name: String,
+    /// The buffer.
+    ring: Arc<Ring<G>>,
+    /// The last known number of goods that have been produced.
+    ///
+    /// Being a [`Cell`], this also prevents `self` from being shared between threads.
+    tail: Cell<usize>,
+}
+
+impl<G> Agent for SpscConsumer<G> {
+    type Good = G;
+}
+
+impl<G> Consumer for SpscConsumer<G> {
+    type Flaws = EmptyStock;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let head = self.ring.head.0.load(Ordering::Relaxed);
+
+        if head == self.tail.get() {
+            self.tail.set(self.ring.tail.0.load(Ordering::Acquire));
+
+            if head == self.tail.get() {
+                throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+            }
+        }
+
+        #[allow(unsafe_code)]
+        // The slot at head is initialized and is only accessible by the consumer until head is incremented.
+        let good = unsafe { (*self.ring.slot(head).get()).as_ptr().read() };
+
+        self.ring
+            .head
+            .0
+            .store(head.wrapping_add(1), Ordering::Release);
+        good
+    }
+}
+
+impl<G> Debug for SpscConsumer<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "SpscConsumer {{ name: {:?}, .. }}", self.name)
+    }
+}
+
+impl<G> Display for SpscConsumer<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Inventory for SpscConsumer<G> {
+    fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(self.ring.capacity)
+    }
+
+    fn is_withdrawn(&self) -> bool {
+        Arc::strong_count(&self.ring) == 1
+    }
+}
+
+/// A bounded queue with a single [`SpscProducer`] and a single [`SpscConsumer`].
+///
+/// The halves synchronize with atomic operations instead of locks, which makes the queue faster than multi-producer multi-consumer queues when only 1 thread produces and only 1 thread consumes.
+#[derive(Debug)]
+pub struct SpscQueue<G> {
+    /// The type of the good that is stored in the queue.
+    good: PhantomData<G>,
+}
+
+impl<G> SpscQueue<G> {
+    /// Creates the [`SpscProducer`] and [`SpscConsumer`] of a queue that stores up to `size` goods.
+    pub fn allocate<S>(name_str: &S, size: usize) -> (SpscProducer<G>, SpscConsumer<G>)
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        split(name_str.as_ref(), Ring::new(size))
+    }
+}
+
+/// Creates the [`SpscProducer`] and [`SpscConsumer`] named `name` that share `ring`.
+fn split<G>(name: &str, ring: Ring<G>) -> (SpscProducer<G>, SpscConsumer<G>) {
+    let head = ring.head.0.load(Ordering::Relaxed);
+    let tail = ring.tail.0.load(Ordering::Relaxed);
+    let ring = Arc::new(ring);
+
+    (
+        SpscProducer {
+            name: String::from(name),
+            ring: Arc::clone(&ring),
+            head: Cell::new(head),
+        },
+        SpscConsumer {
+            name: String::from(name),
+            ring,
+            tail: Cell::new(tail),
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counters_wrap_in_order() {
+        let mut ring = Ring::new(3);
+        *ring.head.0.get_mut() = usize::MAX - 1;
+        *ring.tail.0.get_mut() = usize::MAX - 1;
+        let (producer, consumer) = split("queue", ring);
+
+        for good in 0..3_u8 {
+            assert!(producer.produce(good).is_ok());
+        }
+
+        assert!(producer.produce(3).is_err());
+        assert_eq!(producer.len(), 3);
+        assert_eq!(consumer.consume(), Ok(0));
+        assert!(producer.produce(3).is_ok());
+
+        for good in 1..4 {
+            assert_eq!(consumer.consume(), Ok(good));
+        }
+
+        assert!(consumer.consume().is_err());
+    }
+}
@@ -0,0 +1,261 @@
+//! Implements a [`Consumer`] that consumes from whichever of multiple [`Consumer`]s is ready first.
+use {
+    crate::convert::Adapter,
+    alloc::{boxed::Box, string::String, vec::Vec},
+    core::{
+        cell::Cell,
+        fmt::{self, Debug, Display, Formatter},
+        time::Duration,
+    },
+    fehler::{throw, throws},
+    market::{Agent, Consumer, ConsumptionFlaws, EmptyStock, Failure, Fault, Flaws},
+    std::{thread, time::Instant},
+};
+
+/// The number of steps that [`Backoff`] yields the thread before it begins sleeping.
+const YIELD_STEPS: u32 = 6;
+/// The longest time that [`Backoff`] sleeps.
+const MAX_SLEEP: Duration = Duration::from_millis(1);
+
+/// The defect thrown when the selected [`Consumer`] throws the defect `D`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SelectDefect<D> {
+    /// The index of the [`Consumer`] that threw the defect.
+    index: usize,
+    /// The defect.
+    defect: D,
+}
+
+impl<D> SelectDefect<D> {
+    /// Creates a new [`SelectDefect`] for `defect` thrown by the [`Consumer`] at `index`.
+    pub const fn new(index: usize, defect: D) -> Self {
+        Self { index, defect }
+    }
+
+    /// Returns the index of the [`Consumer`] that threw the defect.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the defect.
+    pub const fn defect(&self) -> &D {
+        &self.defect
+    }
+
+    /// Converts `self` into the defect.
+    #[allow(clippy::missing_const_for_fn)] // Destructors cannot be evaluated at compile-time.
+    pub fn into_defect(self) -> D {
+        self.defect
+    }
+}
+
+impl<D: Display> Display for SelectDefect<D> {
+    /// Writes "consumer {index}: {defect}".
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "consumer {}: {}", self.index, self.defect)
+    }
+}
+
+impl<D: Debug + Display> std::error::Error for SelectDefect<D> {}
+
+/// A [`Consumer`] that consumes goods of type `G` from whichever of multiple [`Consumer`]s is ready first.
+///
+/// Each good is consumed along with the index of the [`Consumer`] that supplied it. The [`Consumer`]s are polled in the order they were pushed, so this is intended as a fallback for [`Consumer`]s that cannot be waited upon together.
+pub struct Selector<G, D> {
+    /// The [`Consumer`]s.
+    consumers: Vec<Box<dyn Consumer<Good = G, Flaws = ConsumptionFlaws<D>>>>,
+    /// The name of the selector.
+    name: String,
+}
+
+impl<G, D> Selector<G, D> {
+    /// Creates a new, empty [`Selector`].
+    #[must_use]
+    pub fn new<S>(name_str: &S) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            consumers: Vec::new(),
+            name: String::from(name_str.as_ref()),
+        }
+    }
+
+    /// Adds `consumer` to the end of the [`Consumer`]s held by `self` and returns its index.
+    pub fn push<C: Consumer + 'static>(&mut self, consumer: C) -> usize
+    where
+        G: From<C::Good> + 'static,
+        D: From<<C::Flaws as Flaws>::Defect> + 'static,
+        EmptyStock: From<<C::Flaws as Flaws>::Insufficiency>,
+    {
+        self.consumers.push(Box::new(Adapter::new(consumer)));
+        self.consumers.len() - 1
+    }
+
+    /// Removes the [`Consumer`] at `index` from `self` and returns it.
+    ///
+    /// The index of each [`Consumer`] after `index` decreases by 1.
+    pub fn remove(
+        &mut self,
+        index: usize,
+    ) -> Option<Box<dyn Consumer<Good = G, Flaws = ConsumptionFlaws<D>>>> {
+        if index < self.consumers.len() {
+            Some(self.consumers.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the next good from the first ready [`Consumer`], blocking until one is available.
+    ///
+    /// If `self` holds no [`Consumer`]s, this blocks forever.
+    ///
+    /// Because a [`Consumer`] is unable to notify `self` when it is supplied, `self` polls its [`Consumer`]s, first yielding the thread between polls and then sleeping for up to 1 millisecond. So a good may be consumed up to 1 millisecond after it is supplied.
+    ///
+    /// # Errors
+    ///
+    /// If no [`Consumer`] supplies a good and a [`Consumer`] throws a defect, throws a [`SelectDefect`] with the index of that [`Consumer`].
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<D>>>)]
+    pub fn consume_wait(&self) -> (usize, G) {
+        let backoff = Backoff::default();
+
+        loop {
+            if let Some(selection) = self.poll()? {
+                break selection;
+            }
+
+            backoff.snooze(None);
+        }
+    }
+
+    /// Retrieves the next good from the first ready [`Consumer`], blocking until one is available or `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// If `timeout` elapses, throws an [`EmptyStock`] insufficiency; if no [`Consumer`] supplies a good and a [`Consumer`] throws a defect, throws a [`SelectDefect`] with the index of that [`Consumer`].
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<D>>>)]
+    pub fn consume_timeout(&self, timeout: Duration) -> (usize, G) {
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            self.consume_deadline(deadline)?
+        } else {
+            self.consume_wait()?
+        }
+    }
+
+    /// Retrieves the next good from the first ready [`Consumer`], blocking until one is available or `deadline` is reached.
+    ///
+    /// The [`Consumer`]s are polled as described by [`Selector::consume_wait()`].
+    ///
+    /// # Errors
+    ///
+    /// If `deadline` is reached, throws an [`EmptyStock`] insufficiency; if no [`Consumer`] supplies a good and a [`Consumer`] throws a defect, throws a [`SelectDefect`] with the index of that [`Consumer`].
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<D>>>)]
+    pub fn consume_deadline(&self, deadline: Instant) -> (usize, G) {
+        let backoff = Backoff::default();
+
+        loop {
+            if let Some(selection) = self.poll()? {
+                break selection;
+            }
+
+            if Instant::now() >= deadline {
+                throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+            }
+
+            backoff.snooze(Some(deadline));
+        }
+    }
+
+    /// Attempts to consume from each [`Consumer`] in order, returning the first good or [`None`] if all are empty.
+    ///
+    /// A defect is only thrown if no [`Consumer`] supplies a good, in which case the defect of the first defective [`Consumer`] is thrown.
+    #[throws(Failure<ConsumptionFlaws<SelectDefect<D>>>)]
+    fn poll(&self) -> Option<(usize, G)> {
+        let mut selection = None;
+        let mut defect = None;
+
+        for (index, consumer) in self.consumers.iter().enumerate() {
+            match consumer.consume() {
+                Ok(good) => {
+                    selection = Some((index, good));
+                    break;
+                }
+                Err(failure) => {
+                    if defect.is_none() && failure.is_defect() {
+                        defect = Some(failure.map_defect(|d| SelectDefect::new(index, d)));
+                    }
+                }
+            }
+        }
+
+        if selection.is_none() {
+            if let Some(failure) = defect {
+                throw!(failure);
+            }
+        }
+
+        selection
+    }
+}
+
+impl<G, D> Agent for Selector<G, D> {
+    type Good = (usize, G);
+}
+
+impl<G, D> Consumer for Selector<G, D> {
+    type Flaws = ConsumptionFlaws<SelectDefect<D>>;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        self.poll()?
+            .ok_or_else(|| self.failure(Fault::Insufficiency(EmptyStock::default())))?
+    }
+}
+
+impl<G, D> Debug for Selector<G, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Selector {{ .. }}")
+    }
+}
+
+impl<G, D> Display for Selector<G, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Selector for {}", self.name)
+    }
+}
+
+/// Paces the attempts of a thread that waits on agents that are unable to notify it.
+///
+/// The first attempts yield the thread; later attempts sleep for a duration that doubles up to [`MAX_SLEEP`]. So a waiting thread uses little processor time but may notice a change up to [`MAX_SLEEP`] late.
+#[derive(Debug, Default)]
+struct Backoff {
+    /// The number of times that the thread has been paced.
+    step: Cell<u32>,
+}
+
+impl Backoff {
+    /// Blocks the thread for the current step and advances to the next step.
+    ///
+    /// The thread is not blocked past `deadline`, if given.
+    fn snooze(&self, deadline: Option<Instant>) {
+        let step = self.step.get();
+
+        if step < YIELD_STEPS {
+            thread::yield_now();
+            self.step.set(step.saturating_add(1));
+        } else {
+            let mut duration =
+                Duration::from_micros(1_u64 << (step - YIELD_STEPS).min(10)).min(MAX_SLEEP);
+
+            if let Some(time) = deadline {
+                duration = duration.min(time.saturating_duration_since(Instant::now()));
+            }
+
+            thread::sleep(duration);
+
+            if duration < MAX_SLEEP {
+                self.step.set(step.saturating_add(1));
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Implements the synchronization used by agents that block until they are able to act.
+use {
+    core::sync::atomic::{self, AtomicUsize, Ordering},
+    std::sync::{Condvar, Mutex, MutexGuard, PoisonError},
+};
+
+#[cfg(any(feature = "std", feature = "crossbeam-queue"))]
+use std::time::Instant;
+
+/// Locks `mutex`, recovering the guard if a thread panicked while holding the lock.
+///
+/// The data protected by the mutexes of this crate is left consistent between statements, so a panic does not invalidate it.
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Wakes threads that are waiting for the state of a market to change.
+///
+/// A notification only locks the [`Mutex`] when a thread is waiting, so an agent that notifies on every action pays for a fence and an atomic load when no thread is waiting.
+#[derive(Debug, Default)]
+pub(crate) struct Signal {
+    /// Incremented by each notification that occurs while a thread is waiting.
+    generation: Mutex<u64>,
+    /// Wakes the waiting threads.
+    condvar: Condvar,
+    /// The number of threads that are waiting.
+    waiter_count: AtomicUsize,
+}
+
+impl Signal {
+    /// Wakes every thread that is waiting on `self`.
+    ///
+    /// Must be called after the change in state so that a waiting thread is able to observe the change.
+    pub(crate) fn notify(&self) {
+        // Pairs with the fence in wait_until(): either this observes the waiter or the waiter observes the change in state.
+        atomic::fence(Ordering::SeqCst);
+
+        if self.waiter_count.load(Ordering::SeqCst) != 0 {
+            let mut generation = lock(&self.generation);
+            *generation = generation.wrapping_add(1);
+            drop(generation);
+            self.condvar.notify_all();
+        }
+    }
+
+    /// Calls `attempt` with `state` until it succeeds, blocking between calls until `self` is notified.
+    ///
+    /// If `deadline` is reached before `attempt` succeeds, returns the state from the last call.
+    #[cfg(any(feature = "std", feature = "crossbeam-queue"))]
+    pub(crate) fn wait_until<T, S, F>(
+        &self,
+        deadline: Option<Instant>,
+        mut state: S,
+        mut attempt: F,
+    ) -> Result<T, S>
+    where
+        F: FnMut(S) -> Result<T, S>,
+    {
+        let _ = self.waiter_count.fetch_add(1, Ordering::SeqCst);
+        atomic::fence(Ordering::SeqCst);
+
+        let outcome = loop {
+            let generation = *lock(&self.generation);
+
+            match attempt(state) {
+                Ok(success) => break Ok(success),
+                Err(unchanged) => state = unchanged,
+            }
+
+            if !self.wait_for_change(generation, deadline) {
+                break Err(state);
+            }
+        };
+
+        let _ = self.waiter_count.fetch_sub(1, Ordering::SeqCst);
+        outcome
+    }
+
+    /// Blocks until a notification occurs after `generation` was read or `deadline` is reached.
+    ///
+    /// Returns if a notification occurred.
+    #[cfg(any(feature = "std", feature = "crossbeam-queue"))]
+    fn wait_for_change(&self, generation: u64, deadline: Option<Instant>) -> bool {
+        let mut guard = lock(&self.generation);
+
+        while *guard == generation {
+            if let Some(time) = deadline {
+                let now = Instant::now();
+
+                if now >= time {
+                    return false;
+                }
+
+                guard = self
+                    .condvar
+                    .wait_timeout(guard, time.saturating_duration_since(now))
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0;
+            } else {
+                guard = self
+                    .condvar
+                    .wait(guard)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+
+        true
+    }
+}
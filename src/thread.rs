@@ -41,7 +41,7 @@ pub struct Thread<A, E> {
     /// Stores the transfer of the [`Outcome`] from the thread.
     ///
     /// Uses an infinite queue so that production cannot fail.
-    outcome_queue: Arc<CrossbeamInfiniteQueue<Outcome<A, E>>>,
+    outcome_queue: CrossbeamInfiniteQueue<Outcome<A, E>>,
 }
 
 impl<A: Send + 'static, E: Send + 'static> Thread<A, E> {
@@ -58,15 +58,15 @@ impl<A: Send + 'static, E: Send + 'static> Thread<A, E> {
     where
         S: AsRef<str> + ?Sized,
     {
-        let outcome_queue = Arc::new(CrossbeamInfiniteQueue::allocate(&format!(
+        let outcome_queue = CrossbeamInfiniteQueue::allocate(&format!(
             "outcome queue for thread `{}`",
             name_str.as_ref()
-        )));
-        let queue_clone = Arc::clone(&outcome_queue);
+        ));
+        let outcome_producer = outcome_queue.producer();
 
         // Drop JoinHandle because Thread will not attempt to join.
         drop(thread::spawn(move || {
-            queue_clone.produce(Self::run(&mut parameters, &mut call))
+            outcome_producer.produce(Self::run(&mut parameters, &mut call))
         }));
 
         Self {
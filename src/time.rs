@@ -0,0 +1,136 @@
+//! Implements [`Consumer`]s that are supplied by the passage of time.
+use {
+    alloc::string::String,
+    core::{
+        cell::Cell,
+        fmt::{self, Display, Formatter},
+        time::Duration,
+    },
+    fehler::{throw, throws},
+    market::{Agent, Consumer, EmptyStock, Failure, Fault},
+    std::time::Instant,
+};
+
+/// A [`Consumer`] that supplies a good each time a period elapses.
+///
+/// The good is the [`Instant`] at which the tick was scheduled. If multiple periods elapse between consumptions, each missed tick is consumed in order.
+#[derive(Debug)]
+pub struct Ticker {
+    /// The name of the ticker.
+    name: String,
+    /// The time between ticks.
+    period: Duration,
+    /// The [`Instant`] at which the next tick is supplied, or [`None`] if it cannot be represented.
+    deadline: Cell<Option<Instant>>,
+}
+
+impl Ticker {
+    /// Creates a new [`Ticker`] that supplies its first tick once `period` has elapsed from now.
+    pub fn new<S>(name_str: &S, period: Duration) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            period,
+            deadline: Cell::new(Instant::now().checked_add(period)),
+        }
+    }
+
+    /// Returns the [`Instant`] at which the next tick will be supplied, or [`None`] if it is too far in the future to be represented, in which case no more ticks are supplied.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline.get()
+    }
+}
+
+impl Agent for Ticker {
+    type Good = Instant;
+}
+
+impl Consumer for Ticker {
+    type Flaws = EmptyStock;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        match self.deadline.get() {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.deadline.set(deadline.checked_add(self.period));
+                deadline
+            }
+            _ => throw!(self.failure(Fault::Insufficiency(EmptyStock::default()))),
+        }
+    }
+}
+
+impl Display for Ticker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A [`Consumer`] that supplies a single good once a delay has elapsed.
+///
+/// The good is the [`Instant`] at which the timer was scheduled to expire. All consumptions after the good has been consumed throw [`EmptyStock`].
+#[derive(Debug)]
+pub struct Timer {
+    /// The name of the timer.
+    name: String,
+    /// The [`Instant`] at which the timer expires, or [`None`] if the good has been consumed or the timer never expires.
+    deadline: Cell<Option<Instant>>,
+}
+
+impl Timer {
+    /// Creates a new [`Timer`] that expires once `delay` has elapsed from now.
+    ///
+    /// If the expiration is too far in the future to be represented, `self` never expires.
+    pub fn new<S>(name_str: &S, delay: Duration) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            deadline: Cell::new(Instant::now().checked_add(delay)),
+        }
+    }
+
+    /// Creates a new [`Timer`] that expires at `deadline`.
+    pub fn at<S>(name_str: &S, deadline: Instant) -> Self
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        Self {
+            name: String::from(name_str.as_ref()),
+            deadline: Cell::new(Some(deadline)),
+        }
+    }
+
+    /// Returns the [`Instant`] at which `self` expires, or [`None`] if its good has been consumed or `self` never expires.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline.get()
+    }
+}
+
+impl Agent for Timer {
+    type Good = Instant;
+}
+
+impl Consumer for Timer {
+    type Flaws = EmptyStock;
+
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        match self.deadline.get() {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.deadline.set(None);
+                deadline
+            }
+            _ => throw!(self.failure(Fault::Insufficiency(EmptyStock::default()))),
+        }
+    }
+}
+
+impl Display for Timer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,179 @@
+//! Implements a channel that shares only the most recently produced good.
+use {
+    crate::{clone_name::CloneName, presence::Presence, sync::lock},
+    alloc::{string::String, sync::Arc},
+    core::{
+        fmt::{self, Display, Formatter},
+        marker::PhantomData,
+        sync::atomic::{AtomicUsize, Ordering},
+    },
+    fehler::{throw, throws},
+    market::{
+        channel::{WithdrawnDemand, WithdrawnSupply},
+        Agent, Consumer, ConsumptionFlaws, EmptyStock, Failure, Fault, Producer, Recall,
+    },
+    std::sync::Mutex,
+};
+
+/// The most recently produced good of a watch channel.
+#[derive(Debug)]
+struct Slot<G> {
+    /// The good, or [`None`] if no good has been produced.
+    good: Option<G>,
+    /// The number of goods that have been produced.
+    version: usize,
+}
+
+/// The [`Producer`] of a [`WatchChannel`].
+///
+/// Each good overwrites the good that was previously produced.
+#[derive(Debug)]
+pub struct WatchSender<G> {
+    /// The name of the sender.
+    name: String,
+    /// The most recent good.
+    slot: Arc<Mutex<Slot<G>>>,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+}
+
+impl<G> Agent for WatchSender<G> {
+    type Good = G;
+}
+
+impl<G> Display for WatchSender<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<G> Producer for WatchSender<G> {
+    type Flaws = WithdrawnDemand;
+
+    /// Replaces the stored good with `good`.
+    ///
+    /// # Errors
+    ///
+    /// If all [`WatchReceiver`]s have been dropped, throws [`WithdrawnDemand`].
+    #[throws(Recall<Self::Flaws, Self::Good>)]
+    fn produce(&self, good: Self::Good) {
+        if self.presence.is_withdrawn() {
+            throw!(self.recall(Fault::Defect(WithdrawnDemand::default()), good));
+        }
+
+        let mut slot = lock(&self.slot);
+        slot.good = Some(good);
+        slot.version = slot.version.wrapping_add(1);
+    }
+}
+
+/// A [`Consumer`] of a [`WatchChannel`].
+///
+/// Each receiver tracks the goods it has seen independently of its clones.
+#[derive(Debug)]
+pub struct WatchReceiver<G> {
+    /// The name of the receiver.
+    name: CloneName,
+    /// The most recent good.
+    slot: Arc<Mutex<Slot<G>>>,
+    /// The version of the last good consumed by `self`.
+    seen_version: AtomicUsize,
+    /// The presence of the agents of the channel.
+    presence: Presence,
+}
+
+impl<G: Clone> WatchReceiver<G> {
+    /// Returns a clone of the most recent good, or [`None`] if no good has been produced.
+    ///
+    /// Unlike [`Consumer::consume`], this does not mark the good as seen.
+    pub fn latest(&self) -> Option<G> {
+        lock(&self.slot).good.clone()
+    }
+}
+
+impl<G> Clone for WatchReceiver<G> {
+    /// Creates a receiver that has seen the same goods as `self`.
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            slot: Arc::clone(&self.slot),
+            seen_version: AtomicUsize::new(self.seen_version.load(Ordering::Relaxed)),
+            presence: self.presence.clone(),
+        }
+    }
+}
+
+impl<G> Agent for WatchReceiver<G> {
+    type Good = G;
+}
+
+impl<G: Clone> Consumer for WatchReceiver<G> {
+    type Flaws = ConsumptionFlaws<WithdrawnSupply>;
+
+    /// Retrieves a clone of the most recent good if it has changed since `self` last consumed.
+    ///
+    /// # Errors
+    ///
+    /// If the good has not changed and the [`WatchSender`] has been dropped, throws [`WithdrawnSupply`]. If the good has not changed otherwise, throws [`EmptyStock`].
+    #[throws(Failure<Self::Flaws>)]
+    fn consume(&self) -> Self::Good {
+        let slot = lock(&self.slot);
+
+        match slot.good {
+            Some(ref good) if slot.version != self.seen_version.load(Ordering::Relaxed) => {
+                self.seen_version.store(slot.version, Ordering::Relaxed);
+                good.clone()
+            }
+            _ => {
+                if self.presence.is_withdrawn() {
+                    throw!(self.failure(Fault::Defect(WithdrawnSupply::default())));
+                } else {
+                    throw!(self.failure(Fault::Insufficiency(EmptyStock::default())));
+                }
+            }
+        }
+    }
+}
+
+impl<G> Display for WatchReceiver<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A channel that stores only the most recently produced good.
+///
+/// Each [`WatchReceiver`] consumes a good only when it has changed since that receiver last consumed, so goods that are overwritten before being consumed are skipped.
+#[derive(Debug)]
+pub struct WatchChannel<G> {
+    /// The type of the good that is exchanged on the channel.
+    good: PhantomData<G>,
+}
+
+impl<G> WatchChannel<G> {
+    /// Creates a [`WatchSender`] and [`WatchReceiver`] of a channel that stores no good.
+    pub fn establish<S>(name_str: &S) -> (WatchSender<G>, WatchReceiver<G>)
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        let slot = Arc::new(Mutex::new(Slot {
+            good: None,
+            version: 0,
+        }));
+        let (sender_presence, receiver_presence) = Presence::pair();
+
+        (
+            WatchSender {
+                name: String::from(name_str.as_ref()),
+                slot: Arc::clone(&slot),
+                presence: sender_presence,
+            },
+            WatchReceiver {
+                name: CloneName::new(name_str.as_ref()),
+                slot,
+                seen_version: AtomicUsize::new(0),
+                presence: receiver_presence,
+            },
+        )
+    }
+}
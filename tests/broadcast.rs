@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+use {
+    market::{Consumer, Fault, Producer},
+    market_types::broadcast::{BroadcastChannel, BroadcastDefect},
+};
+
+#[test]
+fn every_subscriber_consumes_each_good() {
+    let (producer, first) = BroadcastChannel::establish("broadcast", 2);
+    let second = producer.subscribe();
+
+    assert!(producer.produce(1_u8).is_ok());
+    let third = first.subscribe();
+    assert!(producer.produce(2).is_ok());
+
+    assert_eq!(first.consume(), Ok(1));
+    assert_eq!(first.consume(), Ok(2));
+    assert_eq!(second.consume(), Ok(1));
+    assert_eq!(second.consume(), Ok(2));
+    assert_eq!(third.consume(), Ok(2));
+    assert!(!third.consume().unwrap_err().is_defect());
+
+    drop(producer);
+    assert_eq!(
+        third.consume(),
+        Err(third.failure(Fault::Defect(BroadcastDefect::Withdrawn)))
+    );
+}
+
+#[test]
+fn lagged_subscriber() {
+    let (producer, consumer) = BroadcastChannel::establish("broadcast", 2);
+
+    for good in 0..5_u8 {
+        assert!(producer.produce(good).is_ok());
+    }
+
+    assert_eq!(
+        consumer.consume(),
+        Err(consumer.failure(Fault::Defect(BroadcastDefect::Lagged(3))))
+    );
+    assert_eq!(consumer.consume(), Ok(3));
+    assert_eq!(consumer.consume(), Ok(4));
+}
+
+#[test]
+fn unsubscribed() {
+    let (producer, consumer) = BroadcastChannel::establish("broadcast", 2);
+
+    drop(consumer);
+    assert!(producer.produce(1_u8).is_err());
+
+    let consumer = producer.subscribe();
+    assert!(producer.produce(2).is_ok());
+    assert_eq!(consumer.consume(), Ok(2));
+}
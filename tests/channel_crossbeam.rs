@@ -0,0 +1,142 @@
+#![cfg(feature = "crossbeam-channel")]
+use {
+    core::time::Duration,
+    market::{
+        channel::{FiniteChannel, InfiniteChannel},
+        Consumer, Producer,
+    },
+    market_types::channel_crossbeam::{
+        CrossbeamFiniteChannel, CrossbeamInfiniteChannel, CrossbeamRendezvousChannel,
+        CrossbeamSelector, CrossbeamSlidingChannel,
+    },
+    std::thread,
+};
+
+#[test]
+fn close_sender_while_waiting() {
+    let (producer, consumer) = CrossbeamInfiniteChannel::establish("channel");
+    let handle = thread::spawn(move || {
+        assert!(producer.produce(1_u8).is_ok());
+        thread::sleep(Duration::from_millis(20));
+        producer.close();
+        producer
+    });
+
+    assert_eq!(consumer.consume_wait().ok(), Some(1));
+    assert!(consumer.consume_wait().unwrap_err().is_defect());
+    assert!(handle.join().unwrap().produce(2).is_err());
+}
+
+#[test]
+fn close_receiver_while_waiting() {
+    let (producer, consumer) = CrossbeamFiniteChannel::establish("channel", 1);
+    let clone = consumer.clone();
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        clone.close();
+    });
+
+    assert!(producer.produce(1_u8).is_ok());
+    assert!(producer
+        .produce_wait(2)
+        .unwrap_err()
+        .to_string()
+        .contains("withdrawn"));
+    handle.join().unwrap();
+    assert_eq!(consumer.consume().ok(), Some(1));
+    assert!(consumer.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn sliding_evicts_oldest() {
+    let (producer, consumer) = CrossbeamSlidingChannel::establish("channel", 2);
+
+    assert!(producer.produce(1_u8).is_ok());
+    assert!(producer.produce(2).is_ok());
+    assert!(producer.produce(3).is_ok());
+    assert_eq!(producer.eviction_count(), 1);
+    assert_eq!(consumer.consume().ok(), Some(2));
+    assert_eq!(consumer.consume().ok(), Some(3));
+    drop(consumer);
+    assert!(producer.produce(4).is_err());
+}
+
+#[test]
+fn rendezvous_requires_waiting_consumer() {
+    let (producer, consumer) = CrossbeamRendezvousChannel::establish("channel");
+
+    assert!(producer.produce(1_u8).is_err());
+
+    let handle = thread::spawn(move || consumer.consume_wait().ok());
+    assert!(producer.produce_wait(2).is_ok());
+    assert_eq!(handle.join().unwrap(), Some(2));
+}
+
+#[test]
+fn consume_batch() {
+    let (producer, consumer) = CrossbeamInfiniteChannel::establish("channel");
+
+    for good in 0..5_u8 {
+        assert!(producer.produce(good).is_ok());
+    }
+
+    assert_eq!(consumer.consume_batch(3).ok(), Some(vec![0, 1, 2]));
+    drop(producer);
+    assert_eq!(consumer.consume_batch(3).ok(), Some(vec![3, 4]));
+    assert!(consumer.consume_batch(3).is_err());
+}
+
+#[test]
+fn produce_batch() {
+    let (producer, consumer) = CrossbeamFiniteChannel::establish("channel", 3);
+
+    assert_eq!(producer.produce_batch(0..5_u8), vec![3, 4]);
+    assert_eq!(consumer.consume_batch(2).ok(), Some(vec![0, 1]));
+    assert!(producer.produce_batch(vec![5]).is_empty());
+    consumer.close();
+    assert_eq!(producer.produce_batch(vec![6, 7]), vec![6, 7]);
+    assert_eq!(consumer.consume_batch(5).ok(), Some(vec![2, 5]));
+}
+
+#[test]
+fn waits_elapse() {
+    let (producer, consumer) = CrossbeamFiniteChannel::establish("channel", 1);
+
+    assert!(!consumer
+        .consume_timeout(Duration::from_millis(10))
+        .unwrap_err()
+        .is_defect());
+    assert!(producer.produce(1_u8).is_ok());
+    assert!(producer
+        .produce_timeout(2, Duration::from_millis(10))
+        .unwrap_err()
+        .to_string()
+        .contains("insufficient stock"));
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(consumer.consume_wait().ok(), Some(1));
+        consumer
+    });
+
+    assert!(producer.produce_timeout(2, Duration::from_secs(10)).is_ok());
+    assert_eq!(handle.join().unwrap().consume().ok(), Some(2));
+}
+
+#[test]
+fn close_wakes_waiting_selector() {
+    let (producer, consumer) = CrossbeamInfiniteChannel::<u8>::establish("channel");
+    let mut selector = CrossbeamSelector::new("selector");
+    let _ = selector.push(consumer);
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        producer.close();
+        producer
+    });
+
+    assert!(selector
+        .consume_timeout(Duration::from_secs(10))
+        .unwrap_err()
+        .is_defect());
+    drop(handle.join().unwrap());
+}
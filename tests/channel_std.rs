@@ -0,0 +1,159 @@
+#![cfg(feature = "std")]
+use {
+    core::time::Duration,
+    market::{channel::FiniteChannel, Consumer, Producer},
+    market_types::{
+        channel_std::{StdFiniteChannel, StdRendezvousChannel},
+        inventory::Inventory,
+    },
+    std::thread,
+};
+
+#[test]
+fn consume_timeout_empty() {
+    let (_producer, consumer) = StdFiniteChannel::<u8>::establish("channel", 1);
+
+    assert!(!consumer
+        .consume_timeout(Duration::from_millis(10))
+        .unwrap_err()
+        .is_defect());
+}
+
+#[test]
+fn consume_wait_from_other_thread() {
+    let (producer, consumer) = StdFiniteChannel::establish("channel", 1);
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        producer.produce(5_u8).is_ok()
+    });
+
+    assert_eq!(consumer.consume_wait().ok(), Some(5));
+    assert!(handle.join().unwrap());
+    assert!(consumer.consume_wait().unwrap_err().is_defect());
+}
+
+#[test]
+fn produce_timeout_full() {
+    let (producer, consumer) = StdFiniteChannel::establish("channel", 1);
+
+    assert!(producer
+        .produce_timeout(1_u8, Duration::from_millis(10))
+        .is_ok());
+    assert!(producer
+        .produce_timeout(2, Duration::from_millis(10))
+        .is_err());
+    assert_eq!(consumer.consume().ok(), Some(1));
+    drop(consumer);
+    assert!(producer.produce_wait(3).is_err());
+}
+
+#[test]
+fn cloned_senders() {
+    let (producer, consumer) = StdFiniteChannel::establish("channel", 2);
+    let clone = producer.clone();
+
+    assert_eq!(producer.to_string(), "channel");
+    assert_eq!(clone.to_string(), "channel (clone 1)");
+    assert_eq!(clone.clone().to_string(), "channel (clone 2)");
+    assert!(clone.produce(1_u8).is_ok());
+    drop(producer);
+    assert_eq!(consumer.consume().ok(), Some(1));
+    assert!(!consumer.consume().unwrap_err().is_defect());
+    drop(clone);
+    assert!(consumer.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn inventory() {
+    let (producer, consumer) = StdFiniteChannel::establish("channel", 2);
+
+    assert_eq!(consumer.capacity(), Some(2));
+    assert!(consumer.is_empty());
+    assert!(producer.produce(1_u8).is_ok());
+    assert!(producer.produce(2).is_ok());
+    assert!(producer.produce(3).is_err());
+    assert_eq!(producer.len(), 2);
+    assert_eq!(consumer.consume().ok(), Some(1));
+    assert_eq!(consumer.len(), 1);
+    assert!(!consumer.is_withdrawn());
+    drop(producer);
+    assert!(consumer.is_withdrawn());
+    assert_eq!(consumer.len(), 1);
+}
+
+#[test]
+fn batches() {
+    let (producer, consumer) = StdFiniteChannel::establish("channel", 3);
+
+    assert_eq!(producer.produce_batch(0..5_u8), vec![3, 4]);
+    assert_eq!(consumer.len(), 3);
+    assert_eq!(consumer.consume_batch(2).ok(), Some(vec![0, 1]));
+    assert_eq!(consumer.len(), 1);
+    producer.close();
+    assert_eq!(producer.produce_batch(vec![5]), vec![5]);
+    assert_eq!(consumer.consume_batch(3).ok(), Some(vec![2]));
+    assert!(consumer.consume_batch(3).unwrap_err().is_defect());
+}
+
+#[test]
+fn close_sender() {
+    let (producer, consumer) = StdFiniteChannel::establish("channel", 2);
+    let clone = producer.clone();
+
+    assert!(producer.produce(1_u8).is_ok());
+    producer.close();
+    assert!(clone
+        .produce(2)
+        .unwrap_err()
+        .to_string()
+        .contains("withdrawn"));
+    assert!(consumer.is_withdrawn());
+    assert_eq!(consumer.consume().ok(), Some(1));
+    assert!(consumer.consume_wait().unwrap_err().is_defect());
+}
+
+#[test]
+fn close_wakes_waiting_consumer() {
+    let (producer, consumer) = StdRendezvousChannel::<u8>::establish("channel");
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        producer.close();
+        producer
+    });
+
+    assert!(consumer
+        .consume_timeout(Duration::from_secs(10))
+        .unwrap_err()
+        .is_defect());
+    drop(handle.join().unwrap());
+}
+
+#[test]
+fn produce_timeout_wakes_on_consumption() {
+    let (producer, consumer) = StdFiniteChannel::establish("channel", 1);
+
+    assert!(producer.produce(1_u8).is_ok());
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(consumer.consume().ok(), Some(1));
+        consumer
+    });
+
+    assert!(producer.produce_timeout(2, Duration::from_secs(10)).is_ok());
+    assert_eq!(handle.join().unwrap().consume().ok(), Some(2));
+}
+
+#[test]
+fn rendezvous_produce_timeout_waits_for_consumer() {
+    let (producer, consumer) = StdRendezvousChannel::establish("channel");
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        consumer.consume_wait().ok()
+    });
+
+    assert!(producer
+        .produce_timeout(1_u8, Duration::from_secs(10))
+        .is_ok());
+    assert_eq!(handle.join().unwrap(), Some(1));
+}
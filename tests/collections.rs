@@ -0,0 +1,715 @@
+#![cfg(feature = "std")]
+use {
+    market::{
+        channel::{FiniteChannel, WithdrawnDemand, WithdrawnSupply},
+        Consumer, ConsumptionFlaws, Producer, ProductionFlaws,
+    },
+    market_types::{
+        channel_std::StdFiniteChannel,
+        collections::{
+            Balance, BroadcastDistributor, Collector, ComputedRoute, Distributor, Fairness,
+            FanOutPolicy, Keyed, KeyedCollector, LoadBalancer, MergeCollector,
+        },
+        convert::{FilterMapAdapter, FilterMapSpecifier, MapSpecifier},
+    },
+    std::{cmp::Reverse, convert::TryFrom, num::NonZeroUsize, thread, time::Duration},
+};
+
+/// A good produced to the [`Producer`]s of a [`Distributor`].
+#[derive(Debug, PartialEq)]
+struct Routed(u8);
+
+impl TryFrom<u8> for Routed {
+    type Error = u8;
+
+    fn try_from(good: u8) -> Result<Self, Self::Error> {
+        Ok(Self(good))
+    }
+}
+
+impl From<Routed> for u8 {
+    fn from(good: Routed) -> Self {
+        good.0
+    }
+}
+
+/// A key that cannot be cloned.
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct Region(u8);
+
+/// A good that references its key.
+#[derive(Debug, PartialEq)]
+struct Parcel {
+    region: Region,
+    weight: u8,
+}
+
+impl Keyed<Region> for Parcel {
+    fn key(&self) -> &Region {
+        &self.region
+    }
+}
+
+/// A [`Parcel`] produced to the [`Producer`]s of a [`Distributor`].
+#[derive(Debug, PartialEq)]
+struct Delivery(Parcel);
+
+impl TryFrom<Parcel> for Delivery {
+    type Error = Parcel;
+
+    fn try_from(good: Parcel) -> Result<Self, Self::Error> {
+        Ok(Self(good))
+    }
+}
+
+impl From<Delivery> for Parcel {
+    fn from(good: Delivery) -> Self {
+        good.0
+    }
+}
+
+/// A defect of a [`Consumer`] held by a [`MergeCollector`].
+#[derive(Debug)]
+enum Outage {
+    /// The supply of the [`Consumer`] withdrew.
+    Withdrawn,
+}
+
+impl From<WithdrawnSupply> for Outage {
+    fn from(_: WithdrawnSupply) -> Self {
+        Self::Withdrawn
+    }
+}
+
+type Partitions =
+    Distributor<usize, u8, ProductionFlaws<WithdrawnDemand>, ComputedRoute<u8, usize>>;
+
+type Collection = Collector<u8, ConsumptionFlaws<WithdrawnSupply>>;
+
+type Fan = BroadcastDistributor<&'static str, u8, ProductionFlaws<WithdrawnDemand>>;
+
+#[test]
+fn broadcast_best_effort_reports_recalling_keys() {
+    let mut distributor: Fan = BroadcastDistributor::new("fan", FanOutPolicy::BestEffort);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 2);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 1);
+    assert!(distributor.insert("a", a_producer).is_none());
+    assert!(distributor.insert("b", b_producer).is_none());
+
+    assert!(distributor.produce(1_u8).is_ok());
+    assert_eq!(
+        distributor.produce(2).unwrap_err().to_string(),
+        "`fan: recalled by producers [\"b\"]` caused recall of good 2"
+    );
+
+    assert_eq!(a_consumer.consume().ok(), Some(1));
+    assert_eq!(a_consumer.consume().ok(), Some(2));
+    assert_eq!(b_consumer.consume().ok(), Some(1));
+    assert!(b_consumer.consume().is_err());
+}
+
+#[test]
+fn broadcast_fail_fast_stops_at_first_recall() {
+    let mut distributor: Fan = BroadcastDistributor::new("fan", FanOutPolicy::FailFast);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 2);
+    assert!(distributor.insert("a", a_producer).is_none());
+    assert!(distributor.insert("b", b_producer).is_none());
+
+    assert!(distributor.produce(1_u8).is_ok());
+    assert!(distributor.produce(2).is_err());
+
+    assert_eq!(a_consumer.consume().ok(), Some(1));
+    assert_eq!(b_consumer.consume().ok(), Some(1));
+    assert!(b_consumer.consume().is_err());
+}
+
+#[test]
+fn broadcast_all_or_nothing_retries_recalled_goods() {
+    let mut distributor: Fan = BroadcastDistributor::new("fan", FanOutPolicy::AllOrNothing);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 3);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 1);
+    assert!(distributor.insert("a", a_producer).is_none());
+    assert!(distributor.insert("b", b_producer).is_none());
+
+    assert!(distributor.produce(1_u8).is_ok());
+    assert!(distributor.produce(2).is_ok());
+    assert_eq!(
+        distributor.produce(3).unwrap_err().to_string(),
+        "`fan: retaining goods for producers [\"b\"]` caused recall of good 3"
+    );
+
+    assert_eq!(b_consumer.consume().ok(), Some(1));
+    assert!(distributor.produce(3).is_ok());
+
+    assert_eq!(a_consumer.consume().ok(), Some(1));
+    assert_eq!(a_consumer.consume().ok(), Some(2));
+    assert_eq!(a_consumer.consume().ok(), Some(3));
+    assert_eq!(b_consumer.consume().ok(), Some(2));
+    assert!(b_consumer.consume().is_err());
+}
+
+#[test]
+fn broadcast_all_or_nothing_discards_goods_of_replaced_producer() {
+    let mut distributor: Fan = BroadcastDistributor::new("fan", FanOutPolicy::AllOrNothing);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 2);
+    let (b_producer, _b_consumer) = StdFiniteChannel::establish("b", 0);
+    let (c_producer, c_consumer) = StdFiniteChannel::establish("c", 1);
+    assert!(distributor.insert("a", a_producer).is_none());
+    assert!(distributor.insert("b", b_producer).is_none());
+
+    assert!(distributor.produce(1_u8).is_ok());
+    assert!(distributor.insert("b", c_producer).is_some());
+    assert!(distributor.produce(2).is_ok());
+
+    assert_eq!(a_consumer.consume().ok(), Some(1));
+    assert_eq!(a_consumer.consume().ok(), Some(2));
+    assert_eq!(c_consumer.consume().ok(), Some(2));
+    assert!(c_consumer.consume().is_err());
+}
+
+#[test]
+fn broadcast_all_or_nothing_produces_nothing_when_gauged_producer_is_full() {
+    let mut distributor: Fan = BroadcastDistributor::new("fan", FanOutPolicy::AllOrNothing);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 2);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 1);
+    assert!(distributor.insert_gauged("a", a_producer).is_none());
+    assert!(distributor.insert_gauged("b", b_producer).is_none());
+
+    assert!(distributor.produce(1_u8).is_ok());
+    assert_eq!(
+        distributor.produce(2).unwrap_err().to_string(),
+        "`fan: producers [\"b\"] are full` caused recall of good 2"
+    );
+
+    assert_eq!(a_consumer.consume().ok(), Some(1));
+    assert!(a_consumer.consume().is_err());
+    assert_eq!(b_consumer.consume().ok(), Some(1));
+    assert!(distributor.produce(2).is_ok());
+    assert_eq!(a_consumer.consume().ok(), Some(2));
+    assert_eq!(b_consumer.consume().ok(), Some(2));
+}
+
+#[test]
+fn round_robin_retries_next_producer_when_full() {
+    let mut balancer = LoadBalancer::<u8, WithdrawnDemand>::new("balancer", Balance::RoundRobin);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 2);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 1);
+    balancer.push(a_producer);
+    balancer.push(b_producer);
+
+    assert!(balancer.produce(1).is_ok());
+    assert!(balancer.produce(2).is_ok());
+    assert!(balancer.produce(3).is_ok());
+    assert_eq!(
+        balancer.produce(4).unwrap_err().to_string(),
+        "`balancer: insufficient stock` caused recall of good 4"
+    );
+
+    assert_eq!(a_consumer.consume().ok(), Some(1));
+    assert_eq!(a_consumer.consume().ok(), Some(3));
+    assert_eq!(b_consumer.consume().ok(), Some(2));
+}
+
+#[test]
+fn least_loaded_selects_producer_storing_fewest_goods() {
+    let mut balancer = LoadBalancer::<u8, WithdrawnDemand>::new("balancer", Balance::LeastLoaded);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 4);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 4);
+    assert!(a_producer.produce(0).is_ok());
+    balancer.push_gauged(a_producer);
+    balancer.push_gauged(b_producer);
+
+    assert!(balancer.produce(1).is_ok());
+    assert!(balancer.produce(2).is_ok());
+    assert!(balancer.produce(3).is_ok());
+
+    assert_eq!(a_consumer.consume().ok(), Some(0));
+    assert_eq!(a_consumer.consume().ok(), Some(2));
+    assert_eq!(b_consumer.consume().ok(), Some(1));
+    assert_eq!(b_consumer.consume().ok(), Some(3));
+}
+
+#[test]
+fn balancer_throws_defect_without_retrying() {
+    let mut balancer = LoadBalancer::<u8, WithdrawnDemand>::new("balancer", Balance::RoundRobin);
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 1);
+    balancer.push(a_producer);
+    balancer.push(b_producer);
+    drop(a_consumer);
+
+    assert_eq!(
+        balancer.produce(1).unwrap_err().to_string(),
+        "`a: demand has withdrawn` caused recall of good 1"
+    );
+    assert!(b_consumer.consume().is_err());
+}
+
+#[test]
+fn keyed_distributor_does_not_clone_keys() {
+    let mut distributor =
+        Distributor::<Region, Parcel, ProductionFlaws<WithdrawnDemand>>::new("distributor");
+    let (producer, consumer) = StdFiniteChannel::establish("zero", 1);
+    assert!(distributor.insert(Region(0), producer).is_none());
+
+    assert!(distributor
+        .produce(Parcel {
+            region: Region(0),
+            weight: 1
+        })
+        .is_ok());
+    assert!(format!(
+        "{:?}",
+        distributor
+            .produce(Parcel {
+                region: Region(1),
+                weight: 2
+            })
+            .unwrap_err()
+    )
+    .contains("MissingKey(())"));
+
+    assert_eq!(
+        consumer.consume().ok(),
+        Some(Delivery(Parcel {
+            region: Region(0),
+            weight: 1
+        }))
+    );
+}
+
+#[test]
+fn hash_partitions_route_equal_keys_together() {
+    let mut distributor =
+        Partitions::hash_partitioned("partitions", NonZeroUsize::new(2).unwrap(), |good: &u8| {
+            good % 4
+        });
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 4);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 4);
+    assert!(distributor.insert(0, a_producer).is_none());
+    assert!(distributor.insert(1, b_producer).is_none());
+
+    for good in &[1, 5, 9, 13] {
+        assert!(distributor.produce(*good).is_ok());
+    }
+
+    let a_goods: Vec<Routed> = (0..4).filter_map(|_| a_consumer.consume().ok()).collect();
+    let b_goods: Vec<Routed> = (0..4).filter_map(|_| b_consumer.consume().ok()).collect();
+    assert!(a_goods.is_empty() || b_goods.is_empty());
+    assert_eq!(a_goods.len() + b_goods.len(), 4);
+}
+
+#[test]
+fn range_partitions_report_missing_key() {
+    let mut distributor = Partitions::range_partitioned("ranges", vec![10, 20], |good: &u8| *good);
+    let (low_producer, low_consumer) = StdFiniteChannel::establish("low", 2);
+    let (mid_producer, mid_consumer) = StdFiniteChannel::establish("mid", 2);
+    assert!(distributor.insert(0, low_producer).is_none());
+    assert!(distributor.insert(1, mid_producer).is_none());
+
+    assert!(distributor.produce(9).is_ok());
+    assert!(distributor.produce(10).is_ok());
+    assert!(format!("{:?}", distributor.produce(20).unwrap_err()).contains("MissingKey(2)"));
+
+    assert_eq!(low_consumer.consume().ok(), Some(Routed(9)));
+    assert_eq!(mid_consumer.consume().ok(), Some(Routed(10)));
+}
+
+#[test]
+fn fallback_receives_unmatched_goods() {
+    let (fallback_producer, fallback_consumer) = StdFiniteChannel::establish("fallback", 2);
+    let mut distributor = Partitions::with_key("distributor", |good: &u8| usize::from(*good))
+        .with_fallback(fallback_producer);
+    let (producer, consumer) = StdFiniteChannel::establish("zero", 2);
+    assert!(distributor.insert(0, producer).is_none());
+
+    assert!(distributor.produce(0).is_ok());
+    assert!(distributor.produce(1).is_ok());
+
+    assert_eq!(consumer.consume().ok(), Some(Routed(0)));
+    assert_eq!(fallback_consumer.consume().ok(), Some(Routed(1)));
+}
+
+#[test]
+fn dead_letter_receives_goods_rejected_by_defect() {
+    let (dead_producer, dead_consumer) = StdFiniteChannel::establish("dead", 2);
+    let mut distributor = Partitions::with_key("distributor", |good: &u8| usize::from(*good))
+        .with_dead_letter(dead_producer);
+    let (withdrawn_producer, withdrawn_consumer) =
+        StdFiniteChannel::<Routed>::establish("withdrawn", 1);
+    let (full_producer, _full_consumer) = StdFiniteChannel::<Routed>::establish("full", 1);
+    assert!(distributor.insert(0, withdrawn_producer).is_none());
+    assert!(distributor.insert(1, full_producer).is_none());
+    drop(withdrawn_consumer);
+
+    assert!(distributor.produce(0).is_ok());
+    assert!(distributor.produce(2).is_ok());
+    assert!(distributor.produce(1).is_ok());
+    assert!(distributor.produce(1).is_err());
+
+    assert_eq!(dead_consumer.consume().ok(), Some(Routed(0)));
+    assert_eq!(dead_consumer.consume().ok(), Some(Routed(2)));
+    assert!(dead_consumer.consume().is_err());
+}
+
+#[test]
+fn distributor_producers_can_be_removed() {
+    let mut distributor = Partitions::with_key("distributor", |good: &u8| usize::from(*good));
+    let (zero_producer, _zero_consumer) = StdFiniteChannel::<Routed>::establish("zero", 1);
+    let (one_producer, _one_consumer) = StdFiniteChannel::<Routed>::establish("one", 1);
+    assert!(distributor.insert(0, zero_producer).is_none());
+    assert!(distributor.insert(1, one_producer).is_none());
+
+    let mut keys: Vec<usize> = distributor.keys().copied().collect();
+    keys.sort_unstable();
+    assert_eq!(keys, vec![0, 1]);
+
+    assert!(distributor.remove(&0).is_some());
+    assert!(!distributor.contains_key(&0));
+    assert_eq!(distributor.len(), 1);
+    assert!(format!("{:?}", distributor.produce(0).unwrap_err()).contains("MissingKey(0)"));
+}
+
+#[test]
+fn collector_consumers_can_be_removed_by_id() {
+    let mut collector = Collection::new(String::from("collector"));
+    let (a_producer, a_consumer) = StdFiniteChannel::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 1);
+    let a_id = collector.push_with_id(a_consumer);
+    let b_id = collector.push_with_id(b_consumer);
+    assert!(a_producer.produce(1).is_ok());
+    assert!(b_producer.produce(2).is_ok());
+
+    assert!(collector.remove(a_id).is_some());
+    assert!(!collector.contains(a_id));
+    assert_eq!(collector.ids(), vec![b_id]);
+    assert_eq!(collector.consume().ok(), Some(2));
+}
+
+#[test]
+fn pruning_collector_removes_withdrawn_consumers() {
+    let mut collector = Collection::new(String::from("collector")).with_pruning();
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::establish("b", 1);
+    collector.push(a_consumer);
+    let b_id = collector.push_with_id(b_consumer);
+    drop(a_producer);
+    assert!(b_producer.produce(2).is_ok());
+
+    assert_eq!(collector.consume().ok(), Some(2));
+    assert_eq!(collector.ids(), vec![b_id]);
+    assert!(!collector.consume().unwrap_err().is_defect());
+
+    drop(b_producer);
+    assert!(collector.consume().unwrap_err().is_defect());
+    assert!(collector.is_empty());
+}
+
+#[test]
+fn round_robin_collector_alternates_between_busy_consumers() {
+    let mut collector =
+        Collection::new(String::from("collector")).with_fairness(Fairness::RoundRobin);
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 3);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 3);
+    collector.push(a_consumer);
+    collector.push(b_consumer);
+
+    for good in 0..3 {
+        assert!(a_producer.produce(good).is_ok());
+        assert!(b_producer.produce(good + 10).is_ok());
+    }
+
+    let goods: Vec<u8> = (0..6).filter_map(|_| collector.consume().ok()).collect();
+    assert_eq!(goods, vec![0, 10, 1, 11, 2, 12]);
+}
+
+#[test]
+fn round_robin_collector_keeps_turn_after_pruning() {
+    let mut collector = Collection::new(String::from("collector"))
+        .with_fairness(Fairness::RoundRobin)
+        .with_pruning();
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 1);
+    let (c_producer, c_consumer) = StdFiniteChannel::<u8>::establish("c", 1);
+    collector.push(a_consumer);
+    collector.push(b_consumer);
+    collector.push(c_consumer);
+
+    assert!(b_producer.produce(1).is_ok());
+    assert_eq!(collector.consume().ok(), Some(1));
+    drop(a_producer);
+    assert!(!collector.consume().unwrap_err().is_defect());
+    assert_eq!(collector.len(), 2);
+
+    assert!(b_producer.produce(2).is_ok());
+    assert!(c_producer.produce(3).is_ok());
+    assert_eq!(collector.consume().ok(), Some(3));
+    assert_eq!(collector.consume().ok(), Some(2));
+}
+
+#[test]
+fn weighted_collector_consumes_in_proportion_to_weights() {
+    let mut collector =
+        Collection::new(String::from("collector")).with_fairness(Fairness::Weighted);
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 5);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 5);
+    let _ = collector.push_weighted(a_consumer, 2);
+    collector.push(b_consumer);
+
+    for good in 0..5 {
+        assert!(a_producer.produce(good).is_ok());
+        assert!(b_producer.produce(good + 10).is_ok());
+    }
+
+    let goods: Vec<u8> = (0..8).filter_map(|_| collector.consume().ok()).collect();
+    assert_eq!(goods, vec![0, 1, 10, 2, 3, 11, 4, 12]);
+}
+
+#[test]
+fn tolerant_collector_records_defects_and_quarantines_consumer() {
+    let mut collector = Collection::new(String::from("collector")).with_quarantine(2);
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 3);
+    let a_id = collector.push_with_id(a_consumer);
+    collector.push(b_consumer);
+    drop(a_producer);
+
+    for good in 0..3 {
+        assert!(b_producer.produce(good).is_ok());
+    }
+
+    assert_eq!(collector.consume().ok(), Some(0));
+    assert_eq!(collector.consume().ok(), Some(1));
+    assert_eq!(collector.quarantined(), vec![a_id]);
+    assert_eq!(collector.consume().ok(), Some(2));
+
+    let defects = collector.take_defects();
+    assert_eq!(defects.len(), 2);
+    assert!(defects.iter().all(|defect| defect.0 == a_id));
+    assert!(defects[0].1.to_string().starts_with("a: "));
+
+    assert!(collector.release(a_id));
+    assert!(collector.quarantined().is_empty());
+}
+
+#[test]
+fn tolerant_collector_caps_recorded_defects() {
+    let mut collector = Collection::new(String::from("collector")).with_defect_tolerance();
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    collector.push(a_consumer);
+    drop(a_producer);
+
+    for _ in 0..100 {
+        assert!(!collector.consume().unwrap_err().is_defect());
+    }
+
+    assert_eq!(collector.take_defects().len(), 64);
+    assert!(collector.consume().is_err());
+    assert_eq!(collector.take_defects().len(), 1);
+}
+
+#[test]
+fn pruning_takes_precedence_over_tolerance() {
+    let mut collector = Collection::new(String::from("collector"))
+        .with_defect_tolerance()
+        .with_pruning();
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    collector.push(a_consumer);
+    drop(a_producer);
+
+    assert!(collector.consume().unwrap_err().is_defect());
+    assert!(collector.is_empty());
+    assert!(collector.take_defects().is_empty());
+}
+
+#[test]
+fn merge_collector_waits_for_empty_sources() {
+    let mut collector = MergeCollector::<u8, ConsumptionFlaws<WithdrawnSupply>>::new("merge");
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 2);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 3);
+    collector.push(a_consumer);
+    collector.push(b_consumer);
+
+    for good in &[1, 4] {
+        assert!(a_producer.produce(*good).is_ok());
+    }
+
+    for good in &[2, 3, 5] {
+        assert!(b_producer.produce(*good).is_ok());
+    }
+
+    let goods: Vec<u8> = (0..4).filter_map(|_| collector.consume().ok()).collect();
+    assert_eq!(goods, vec![1, 2, 3, 4]);
+    assert!(!collector.consume().unwrap_err().is_defect());
+
+    drop(a_producer);
+    assert!(collector.consume().unwrap_err().is_defect());
+    assert_eq!(collector.consume().ok(), Some(5));
+
+    drop(b_producer);
+    assert!(collector.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn merge_collector_throws_defect_of_its_consumers() {
+    let mut collector = MergeCollector::<u8, ConsumptionFlaws<Outage>>::new("merge");
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 1);
+    collector.push(a_consumer);
+    collector.push(b_consumer);
+    assert!(b_producer.produce(1).is_ok());
+    drop(a_producer);
+
+    let failure = collector.consume().unwrap_err();
+    assert!(failure.is_defect());
+    assert!(format!("{:?}", failure).contains("Defect(Withdrawn)"));
+    assert_eq!(collector.consume().ok(), Some(1));
+    assert!(!collector.consume().unwrap_err().is_defect());
+    assert!(b_producer.produce(2).is_ok());
+    assert_eq!(collector.consume().ok(), Some(2));
+
+    drop(b_producer);
+    assert!(collector.consume().unwrap_err().is_defect());
+    assert!(collector.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn merge_collector_stops_waiting_after_timeout() {
+    let mut collector =
+        MergeCollector::<u8, ConsumptionFlaws<WithdrawnSupply>>::with_key("merge", |good| {
+            Reverse(*good)
+        })
+        .with_timeout(Duration::from_millis(10));
+    let (_a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 2);
+    collector.push(a_consumer);
+    collector.push(b_consumer);
+
+    assert!(b_producer.produce(2).is_ok());
+    assert!(b_producer.produce(1).is_ok());
+    assert!(!collector.consume().unwrap_err().is_defect());
+
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(collector.consume().ok(), Some(2));
+    assert_eq!(collector.consume().ok(), Some(1));
+}
+
+#[test]
+fn keyed_collector_tags_goods_with_source_key() {
+    let mut collector =
+        KeyedCollector::<&str, u8, ConsumptionFlaws<WithdrawnSupply>>::new("collector");
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 1);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 1);
+    assert!(collector.insert("a", a_consumer).is_none());
+    assert!(collector.insert("b", b_consumer).is_none());
+    assert_eq!(
+        collector.keys().copied().collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+
+    assert!(b_producer.produce(2).is_ok());
+    assert_eq!(collector.consume().ok(), Some(("b", 2)));
+    assert!(a_producer.produce(1).is_ok());
+    assert_eq!(collector.consume().ok(), Some(("a", 1)));
+
+    assert!(collector.remove(&"a").is_some());
+    assert!(!collector.contains_key(&"a"));
+    assert!(!collector.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn keyed_collector_uses_fairness_and_pruning() {
+    let mut collector =
+        KeyedCollector::<&str, u8, ConsumptionFlaws<WithdrawnSupply>>::new("collector")
+            .with_fairness(Fairness::RoundRobin)
+            .with_pruning();
+    let (a_producer, a_consumer) = StdFiniteChannel::<u8>::establish("a", 2);
+    let (b_producer, b_consumer) = StdFiniteChannel::<u8>::establish("b", 2);
+    assert!(collector.insert("a", a_consumer).is_none());
+    assert!(collector.insert("b", b_consumer).is_none());
+
+    for good in 0..2 {
+        assert!(a_producer.produce(good).is_ok());
+        assert!(b_producer.produce(good + 10).is_ok());
+    }
+
+    assert_eq!(collector.consume().ok(), Some(("a", 0)));
+    assert_eq!(collector.consume().ok(), Some(("b", 10)));
+    assert_eq!(collector.consume().ok(), Some(("a", 1)));
+    drop(a_producer);
+    assert_eq!(collector.consume().ok(), Some(("b", 11)));
+    assert!(!collector.consume().unwrap_err().is_defect());
+    assert!(!collector.contains_key(&"a"));
+    assert_eq!(collector.keys().copied().collect::<Vec<_>>(), vec!["b"]);
+    assert!(collector.remove(&"a").is_none());
+
+    let (_c_producer, c_consumer) = StdFiniteChannel::<u8>::establish("c", 1);
+    assert!(collector.insert("a", c_consumer).is_none());
+    assert_eq!(
+        collector.keys().copied().collect::<Vec<_>>(),
+        vec!["b", "a"]
+    );
+    assert!(collector.remove(&"b").is_some());
+    assert_eq!(collector.keys().copied().collect::<Vec<_>>(), vec!["a"]);
+}
+
+#[test]
+fn closures_convert_goods_of_distributor_and_collector() {
+    let mut distributor = Distributor::<
+        usize,
+        u16,
+        ProductionFlaws<WithdrawnDemand>,
+        ComputedRoute<u16, usize>,
+    >::with_key("distributor", |_: &u16| 0);
+    let (producer, consumer) = StdFiniteChannel::<u8>::establish("channel", 2);
+    assert!(distributor
+        .insert_specified(
+            0,
+            FilterMapSpecifier::new(
+                producer,
+                |good: u16| u8::try_from(good).map_err(|_| good),
+                u16::from
+            ),
+        )
+        .is_none());
+
+    assert!(distributor.produce(2).is_ok());
+    assert!(format!("{:?}", distributor.produce(300).unwrap_err()).contains("Unspecifiable"));
+    assert!(distributor.produce(3).is_ok());
+
+    let mut collector =
+        Collector::<String, ConsumptionFlaws<WithdrawnSupply>>::new(String::from("collector"));
+    collector.push(FilterMapAdapter::<
+        _,
+        String,
+        ConsumptionFlaws<WithdrawnSupply>,
+        _,
+        _,
+    >::new(
+        consumer,
+        |good: u8| (good > 2).then(|| good.to_string()),
+        |defect| defect,
+    ));
+
+    assert_eq!(collector.consume().ok(), Some(String::from("3")));
+}
+
+#[test]
+fn map_specifier_unmaps_recalled_goods() {
+    let (producer, consumer) = StdFiniteChannel::<u8>::establish("channel", 1);
+    let specifier = MapSpecifier::<_, (u8, &str), ProductionFlaws<WithdrawnDemand>, _, _>::new(
+        producer,
+        |good: (u8, &str)| good.0,
+        |good| (good, "recalled"),
+    );
+
+    assert!(specifier.produce((1, "sent")).is_ok());
+
+    let full = format!("{:?}", specifier.produce((2, "sent")).unwrap_err());
+    assert!(full.contains("(2, \"recalled\")"));
+    assert!(full.contains("Insufficiency(FullStock)"));
+
+    drop(consumer);
+    assert!(format!("{:?}", specifier.produce((3, "sent")).unwrap_err()).contains("Recalled"));
+}
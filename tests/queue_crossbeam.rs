@@ -0,0 +1,80 @@
+#![cfg(feature = "crossbeam-queue")]
+use {
+    core::time::Duration,
+    market::{
+        queue::{FiniteQueue, InfiniteQueue},
+        Consumer, Producer,
+    },
+    market_types::queue_crossbeam::{CrossbeamFiniteQueue, CrossbeamInfiniteQueue},
+    std::thread,
+};
+
+#[test]
+fn infinite_batch() {
+    let queue = CrossbeamInfiniteQueue::allocate("queue");
+
+    queue.produce_batch(0..5_u8);
+    assert_eq!(queue.consume_batch(3), vec![0, 1, 2]);
+    assert_eq!(queue.consume_batch(3), vec![3, 4]);
+    assert!(queue.consume_batch(3).is_empty());
+}
+
+#[test]
+fn finite_batch() {
+    let queue = CrossbeamFiniteQueue::allocate("queue", 3);
+
+    assert_eq!(queue.produce_batch(0..5_u8), vec![3, 4]);
+    assert_eq!(queue.consume_batch(2), vec![0, 1]);
+    assert!(queue.produce_batch(vec![5]).is_empty());
+    assert_eq!(queue.consume_batch(5), vec![2, 5]);
+}
+
+#[test]
+fn shared_handles() {
+    let queue = CrossbeamFiniteQueue::allocate("queue", 4);
+    let clone = queue.clone();
+    let producer = queue.producer();
+    let consumer = queue.consumer();
+
+    assert_eq!(clone.to_string(), "queue (clone 1)");
+    thread::spawn(move || {
+        assert!(producer.produce(1_u8).is_ok());
+        assert!(producer.produce(2).is_ok());
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(consumer.consume(), Ok(1));
+    assert_eq!(clone.consume(), Ok(2));
+    assert!(queue.consume().is_err());
+}
+
+#[test]
+fn infinite_wait_wakes_on_produce() {
+    let queue = CrossbeamInfiniteQueue::allocate("queue");
+    let producer = queue.producer();
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        assert!(producer.produce(1_u8).is_ok());
+    });
+
+    assert_eq!(queue.consume_wait(), 1);
+    handle.join().unwrap();
+    assert!(queue.consume_timeout(Duration::from_millis(10)).is_err());
+}
+
+#[test]
+fn finite_wait_wakes_on_produce() {
+    let queue = CrossbeamFiniteQueue::allocate("queue", 1);
+    let producer = queue.producer();
+
+    assert!(queue.consume_timeout(Duration::from_millis(10)).is_err());
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        assert!(producer.produce(1_u8).is_ok());
+    });
+
+    assert_eq!(queue.consume_timeout(Duration::from_secs(10)), Ok(1));
+    handle.join().unwrap();
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "std")]
+use {
+    core::time::Duration,
+    market::{queue::InfiniteQueue, Consumer, Producer},
+    market_types::queue_delay::DelayQueue,
+    std::{thread, time::Instant},
+};
+
+#[test]
+fn consumes_when_ready() {
+    let queue = DelayQueue::allocate("queue");
+
+    assert!(queue.produce(3).is_ok());
+
+    let start = Instant::now();
+
+    queue.produce_after(1_u8, Duration::from_secs(3600));
+    queue.produce_at(2, start + Duration::from_millis(20));
+    assert_eq!(queue.consume(), Ok(3));
+    assert_eq!(queue.next_ready(), Some(start + Duration::from_millis(20)));
+
+    thread::sleep(
+        queue
+            .next_ready()
+            .unwrap()
+            .saturating_duration_since(Instant::now()),
+    );
+    assert_eq!(queue.consume(), Ok(2));
+    assert!(queue.consume().is_err());
+    assert!(queue.next_ready().unwrap() > start + Duration::from_secs(3000));
+}
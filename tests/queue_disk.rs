@@ -0,0 +1,203 @@
+#![cfg(feature = "std")]
+use {
+    market::{queue::InfiniteQueue, Consumer, Producer},
+    market_types::{
+        inventory::Inventory,
+        queue_disk::{DiskInfiniteQueue, DiskQueue, StringCodec, SyncPolicy},
+    },
+    std::{
+        env, fs,
+        io::{Seek, SeekFrom, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+/// A directory that is removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("market_types-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        Self(path)
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0).or_else(|_| fs::remove_file(&self.0));
+    }
+}
+
+fn segment(dir: &TempDir) -> PathBuf {
+    fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.extension() == Some("segment".as_ref()))
+        .unwrap()
+}
+
+fn open(dir: &TempDir) -> DiskQueue<String, StringCodec> {
+    DiskQueue::open("queue", dir.path(), StringCodec).unwrap()
+}
+
+#[test]
+fn uncommitted_goods_survive_reopen() {
+    let dir = TempDir::new("reopen");
+    let queue = open(&dir);
+
+    for good in &["a", "b", "c"] {
+        assert!(queue.produce(String::from(*good)).is_ok());
+    }
+
+    assert_eq!(queue.consume().ok(), Some(String::from("a")));
+    queue.commit().unwrap();
+    assert_eq!(queue.consume().ok(), Some(String::from("b")));
+    drop(queue);
+
+    let queue = open(&dir);
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.consume().ok(), Some(String::from("b")));
+    queue.rollback().unwrap();
+    assert_eq!(queue.consume().ok(), Some(String::from("b")));
+    assert_eq!(queue.consume().ok(), Some(String::from("c")));
+    assert!(!queue.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn recovers_from_interrupted_write() {
+    let dir = TempDir::new("recover");
+    let queue = open(&dir);
+
+    assert!(queue.produce(String::from("kept")).is_ok());
+    drop(queue);
+
+    fs::OpenOptions::new()
+        .append(true)
+        .open(segment(&dir))
+        .unwrap()
+        .write_all(&[9, 0, 0, 0, 1, 2])
+        .unwrap();
+
+    let queue = open(&dir);
+    assert!(queue.produce(String::from("added")).is_ok());
+    assert_eq!(queue.consume().ok(), Some(String::from("kept")));
+    assert_eq!(queue.consume().ok(), Some(String::from("added")));
+}
+
+#[test]
+fn commit_removes_consumed_segments() {
+    let dir = TempDir::new("segments");
+    let queue = open(&dir)
+        .with_segment_size(16)
+        .with_sync_policy(SyncPolicy::EveryCount(2));
+    let segment_count = || {
+        fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().path().extension() == Some("segment".as_ref()))
+            .count()
+    };
+
+    for good in &["first", "second", "third"] {
+        assert!(queue.produce(String::from(*good)).is_ok());
+    }
+
+    assert_eq!(segment_count(), 3);
+    assert_eq!(queue.consume().ok(), Some(String::from("first")));
+    assert_eq!(queue.consume().ok(), Some(String::from("second")));
+    queue.commit().unwrap();
+    assert_eq!(segment_count(), 1);
+    assert_eq!(queue.consume().ok(), Some(String::from("third")));
+}
+
+#[test]
+fn corrupted_record_is_skipped() {
+    let dir = TempDir::new("corrupt");
+    let queue = open(&dir);
+
+    assert!(queue.produce(String::from("bad")).is_ok());
+    assert!(queue.produce(String::from("good")).is_ok());
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .open(segment(&dir))
+        .unwrap();
+    let _ = file.seek(SeekFrom::Start(8)).unwrap();
+    file.write_all(b"x").unwrap();
+
+    let failure = queue.consume().unwrap_err();
+    assert!(failure.is_defect());
+    assert!(failure
+        .to_string()
+        .contains("corrupted record in segment 0 at offset 0"));
+    assert_eq!(queue.consume().ok(), Some(String::from("good")));
+    assert!(!queue.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn corrupted_middle_record_survives_reopen() {
+    let dir = TempDir::new("corrupt-reopen");
+    let queue = open(&dir);
+
+    assert!(queue.produce(String::from("first")).is_ok());
+    assert!(queue.produce(String::from("bad")).is_ok());
+    assert!(queue.produce(String::from("last")).is_ok());
+    drop(queue);
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .open(segment(&dir))
+        .unwrap();
+    let _ = file.seek(SeekFrom::Start(21)).unwrap();
+    file.write_all(b"x").unwrap();
+    drop(file);
+
+    let queue = open(&dir);
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.consume().ok(), Some(String::from("first")));
+    assert!(queue
+        .consume()
+        .unwrap_err()
+        .to_string()
+        .contains("corrupted record in segment 0 at offset 13"));
+    assert_eq!(queue.consume().ok(), Some(String::from("last")));
+}
+
+#[test]
+fn infinite_queue_survives_reopen() {
+    let dir = TempDir::new("infinite");
+    let name = dir.path().to_str().unwrap();
+    let queue = DiskInfiniteQueue::<String, StringCodec>::allocate(name);
+
+    assert!(queue.produce(String::from("a")).is_ok());
+    assert!(queue.produce(String::from("b")).is_ok());
+    assert_eq!(queue.consume(), Ok(String::from("a")));
+    queue.disk().unwrap().commit().unwrap();
+    drop(queue);
+
+    let queue = DiskInfiniteQueue::<String, StringCodec>::allocate(name);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.consume(), Ok(String::from("b")));
+    assert!(queue.consume().is_err());
+    assert!(queue.defect_consumer().unwrap().consume().is_err());
+    assert!(queue.defect_consumer().is_none());
+}
+
+#[test]
+fn infinite_queue_reports_defects() {
+    let dir = TempDir::new("infinite-defect");
+    fs::write(dir.path(), b"not a directory").unwrap();
+
+    let queue = DiskInfiniteQueue::<String, StringCodec>::allocate(dir.path().to_str().unwrap());
+    let defects = queue.defect_consumer().unwrap();
+
+    assert!(queue.disk().is_none());
+    assert!(defects.consume().is_ok());
+    assert!(queue.produce(String::from("held")).is_ok());
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.consume(), Ok(String::from("held")));
+}
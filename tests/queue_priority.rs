@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+use {
+    market::{
+        queue::{FiniteQueue, InfiniteQueue},
+        Consumer, Producer,
+    },
+    market_types::queue_priority::{Overflow, PriorityFiniteQueue, PriorityInfiniteQueue},
+};
+
+#[test]
+fn highest_priority_first() {
+    let queue = PriorityInfiniteQueue::allocate("queue");
+
+    for good in &[2_u8, 5, 1, 4] {
+        assert!(queue.produce(*good).is_ok());
+    }
+
+    assert_eq!(queue.consume(), Ok(5));
+    assert_eq!(queue.consume(), Ok(4));
+    assert_eq!(queue.consume(), Ok(2));
+    assert_eq!(queue.consume(), Ok(1));
+    assert!(queue.consume().is_err());
+}
+
+#[test]
+fn key_keeps_order_of_equal_priorities() {
+    let queue = PriorityInfiniteQueue::with_key("queue", |good: &(u8, char)| good.0);
+
+    for good in &[(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd')] {
+        assert!(queue.produce(*good).is_ok());
+    }
+
+    assert_eq!(queue.consume(), Ok((2, 'b')));
+    assert_eq!(queue.consume(), Ok((2, 'd')));
+    assert_eq!(queue.consume(), Ok((1, 'a')));
+    assert_eq!(queue.consume(), Ok((1, 'c')));
+}
+
+#[test]
+fn full() {
+    let rejecting = PriorityFiniteQueue::allocate("rejecting", 2);
+    let evicting =
+        PriorityFiniteQueue::allocate("evicting", 2).with_overflow(Overflow::EvictLowest);
+
+    for queue in &[&rejecting, &evicting] {
+        assert!(queue.produce(2_u8).is_ok());
+        assert!(queue.produce(3).is_ok());
+        assert!(queue.produce(1).is_err());
+    }
+
+    assert!(rejecting.produce(4).is_err());
+    assert!(evicting.produce(4).is_ok());
+
+    assert_eq!(rejecting.consume(), Ok(3));
+    assert_eq!(rejecting.consume(), Ok(2));
+    assert_eq!(evicting.consume(), Ok(4));
+    assert_eq!(evicting.consume(), Ok(3));
+    assert!(evicting.consume().is_err());
+}
@@ -0,0 +1,118 @@
+use {
+    market::{Consumer, Producer},
+    market_types::queue_spsc::SpscQueue,
+    std::thread,
+};
+
+#[test]
+fn full_and_empty() {
+    let (producer, consumer) = SpscQueue::allocate("queue", 2);
+
+    assert!(consumer.consume().is_err());
+    assert!(producer.produce(1_u8).is_ok());
+    assert!(producer.produce(2).is_ok());
+    assert!(producer.produce(3).is_err());
+    assert_eq!(consumer.consume(), Ok(1));
+    assert!(producer.produce(3).is_ok());
+    assert_eq!(consumer.consume(), Ok(2));
+    assert_eq!(consumer.consume(), Ok(3));
+    assert!(consumer.consume().is_err());
+}
+
+#[test]
+fn drops_remaining_goods() {
+    let good = std::sync::Arc::new(());
+    let (producer, consumer) = SpscQueue::allocate("queue", 4);
+
+    assert!(producer.produce(std::sync::Arc::clone(&good)).is_ok());
+    assert!(producer.produce(std::sync::Arc::clone(&good)).is_ok());
+    drop(consumer.consume());
+    drop(producer);
+    drop(consumer);
+    assert_eq!(std::sync::Arc::strong_count(&good), 1);
+}
+
+#[test]
+fn across_threads() {
+    let (producer, consumer) = SpscQueue::allocate("queue", 8);
+    let handle = thread::spawn(move || {
+        for good in 0..10_000_u32 {
+            while producer.produce(good).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+
+    for expected in 0..10_000_u32 {
+        loop {
+            if let Ok(good) = consumer.consume() {
+                assert_eq!(good, expected);
+                break;
+            }
+
+            thread::yield_now();
+        }
+    }
+
+    handle.join().unwrap();
+}
+
+/// Compares moving goods between 2 threads via [`SpscQueue`] and [`CrossbeamFiniteQueue`].
+///
+/// Run with `--nocapture` to see the results.
+#[cfg(feature = "crossbeam-queue")]
+#[test]
+fn benchmark_against_crossbeam() {
+    use {
+        market::queue::FiniteQueue, market_types::queue_crossbeam::CrossbeamFiniteQueue,
+        std::time::Instant,
+    };
+
+    const COUNT: u64 = 200_000;
+    const SIZE: usize = 1024;
+
+    fn transfer<P, C>(producer: P, consumer: &C) -> u64
+    where
+        P: Producer<Good = u64> + Send + 'static,
+        C: Consumer<Good = u64>,
+    {
+        let handle = thread::spawn(move || {
+            for good in 0..COUNT {
+                while producer.produce(good).is_err() {
+                    thread::yield_now();
+                }
+            }
+        });
+        let mut sum = 0;
+        let mut received = 0;
+
+        while received < COUNT {
+            if let Ok(good) = consumer.consume() {
+                sum += good;
+                received += 1;
+            } else {
+                thread::yield_now();
+            }
+        }
+
+        handle.join().unwrap();
+        sum
+    }
+
+    let expected = COUNT * (COUNT - 1) / 2;
+
+    let (producer, consumer) = SpscQueue::allocate("spsc", SIZE);
+    let start = Instant::now();
+    assert_eq!(transfer(producer, &consumer), expected);
+    let spsc = start.elapsed();
+
+    let queue = CrossbeamFiniteQueue::allocate("crossbeam", SIZE);
+    let start = Instant::now();
+    assert_eq!(transfer(queue.producer(), &queue), expected);
+    let crossbeam = start.elapsed();
+
+    println!(
+        "moved {} goods: SpscQueue {:?}, CrossbeamFiniteQueue {:?}",
+        COUNT, spsc, crossbeam
+    );
+}
@@ -0,0 +1,139 @@
+#![cfg(all(feature = "std", feature = "crossbeam-channel"))]
+use {
+    core::time::Duration,
+    market::{
+        channel::{FiniteChannel, InfiniteChannel, WithdrawnSupply},
+        Consumer, Producer,
+    },
+    market_types::{
+        channel_crossbeam::{CrossbeamInfiniteChannel, CrossbeamSelector},
+        channel_std::StdFiniteChannel,
+        select::Selector,
+    },
+    std::thread,
+};
+
+#[test]
+fn crossbeam_selector_consumes_ready_receiver() {
+    let (first_producer, first_consumer) = CrossbeamInfiniteChannel::establish("first");
+    let (second_producer, second_consumer) = CrossbeamInfiniteChannel::establish("second");
+    let mut selector = CrossbeamSelector::new("selector");
+    assert_eq!(selector.push(first_consumer), 0);
+    assert_eq!(selector.push(second_consumer), 1);
+
+    assert!(selector.consume().is_err());
+    assert!(second_producer.produce(2_u8).is_ok());
+    assert_eq!(selector.consume().ok(), Some((1, 2)));
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        first_producer.produce(1).is_ok()
+    });
+    assert_eq!(selector.consume_wait().ok(), Some((0, 1)));
+    assert!(handle.join().unwrap());
+    assert!(selector
+        .consume_timeout(Duration::from_millis(10))
+        .unwrap_err()
+        .is_defect());
+    drop(second_producer);
+}
+
+#[test]
+fn selector_consumes_ready_consumer() {
+    let (first_producer, first_consumer) = StdFiniteChannel::<u8>::establish("first", 1);
+    let (second_producer, second_consumer) = StdFiniteChannel::<u8>::establish("second", 1);
+    let mut selector = Selector::<u8, WithdrawnSupply>::new("selector");
+    assert_eq!(selector.push(first_consumer), 0);
+    assert_eq!(selector.push(second_consumer), 1);
+
+    assert!(!selector
+        .consume_timeout(Duration::from_millis(10))
+        .unwrap_err()
+        .is_defect());
+    assert!(second_producer.produce(2).is_ok());
+    assert_eq!(selector.consume_wait().ok(), Some((1, 2)));
+
+    drop(first_producer);
+    assert!(selector.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn selector_wait_wakes_on_supply() {
+    let (first_producer, first_consumer) = StdFiniteChannel::<u8>::establish("first", 1);
+    let (second_producer, second_consumer) = StdFiniteChannel::<u8>::establish("second", 1);
+    let mut selector = Selector::<u8, WithdrawnSupply>::new("selector");
+    let _ = selector.push(first_consumer);
+    let _ = selector.push(second_consumer);
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        second_producer.produce(2).is_ok()
+    });
+
+    assert_eq!(
+        selector.consume_timeout(Duration::from_secs(10)).ok(),
+        Some((1, 2))
+    );
+    assert!(handle.join().unwrap());
+    drop(first_producer);
+}
+
+#[test]
+fn selector_prefers_goods_over_defects() {
+    let (first_producer, first_consumer) = StdFiniteChannel::<u8>::establish("first", 1);
+    let (second_producer, second_consumer) = StdFiniteChannel::<u8>::establish("second", 1);
+    let mut selector = Selector::<u8, WithdrawnSupply>::new("selector");
+    let _ = selector.push(first_consumer);
+    let _ = selector.push(second_consumer);
+
+    drop(first_producer);
+    assert!(second_producer.produce(2).is_ok());
+    assert_eq!(selector.consume().ok(), Some((1, 2)));
+    assert!(selector
+        .consume()
+        .unwrap_err()
+        .to_string()
+        .contains("consumer 0"));
+}
+
+#[test]
+fn empty_crossbeam_selector_times_out() {
+    let selector = CrossbeamSelector::<u8>::new("selector");
+
+    assert!(!selector
+        .consume_timeout(Duration::from_millis(10))
+        .unwrap_err()
+        .is_defect());
+}
+
+#[test]
+fn crossbeam_selector_removes_exhausted_receiver() {
+    let (first_producer, first_consumer) = CrossbeamInfiniteChannel::<u8>::establish("first");
+    let (second_producer, second_consumer) = CrossbeamInfiniteChannel::establish("second");
+    let mut selector = CrossbeamSelector::new("selector");
+    let _ = selector.push(first_consumer);
+    let _ = selector.push(second_consumer);
+
+    drop(first_producer);
+    assert!(selector.consume().unwrap_err().is_defect());
+    assert!(selector.remove(0).is_some());
+    assert!(selector.remove(1).is_none());
+    assert!(!selector.consume().unwrap_err().is_defect());
+    assert!(second_producer.produce(2).is_ok());
+    assert_eq!(selector.consume().ok(), Some((0, 2)));
+}
+
+#[test]
+fn selector_removes_withdrawn_consumer() {
+    let (first_producer, first_consumer) = StdFiniteChannel::<u8>::establish("first", 1);
+    let (second_producer, second_consumer) = StdFiniteChannel::<u8>::establish("second", 1);
+    let mut selector = Selector::<u8, WithdrawnSupply>::new("selector");
+    let _ = selector.push(first_consumer);
+    let _ = selector.push(second_consumer);
+
+    drop(first_producer);
+    assert!(selector.consume().unwrap_err().is_defect());
+    assert!(selector.remove(0).is_some());
+    assert!(!selector.consume().unwrap_err().is_defect());
+    assert!(second_producer.produce(2).is_ok());
+    assert_eq!(selector.consume().ok(), Some((0, 2)));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+use {
+    core::time::Duration,
+    market::{Consumer, EmptyStock, Fault},
+    market_types::time::{Ticker, Timer},
+    std::{thread, time::Instant},
+};
+
+#[test]
+fn ticker_supplies_each_period() {
+    let ticker = Ticker::new("ticker", Duration::from_millis(50));
+    let first = ticker.deadline().unwrap();
+
+    let early = ticker.consume();
+
+    // Only checked when the deadline has certainly not passed, which a loaded machine may not guarantee.
+    if Instant::now() < first {
+        assert_eq!(
+            early,
+            Err(ticker.failure(Fault::Insufficiency(EmptyStock::default())))
+        );
+    }
+
+    thread::sleep(Duration::from_millis(120));
+    // At least 2 periods have elapsed; more may have on a loaded machine.
+    let mut ticks: Vec<Instant> = early.into_iter().collect();
+
+    while ticks.len() < 2 {
+        ticks.push(ticker.consume().unwrap());
+    }
+
+    assert_eq!(ticks, vec![first, first + Duration::from_millis(50)]);
+}
+
+#[test]
+fn ticker_beyond_representable_time_never_ticks() {
+    let ticker = Ticker::new("ticker", Duration::MAX);
+
+    assert_eq!(ticker.deadline(), None);
+    assert_eq!(
+        ticker.consume(),
+        Err(ticker.failure(Fault::Insufficiency(EmptyStock::default())))
+    );
+}
+
+#[test]
+fn timer_supplies_once() {
+    let timer = Timer::new("timer", Duration::from_millis(50));
+    let deadline = timer.deadline().unwrap();
+
+    let early = timer.consume();
+
+    // Only checked when the deadline has certainly not passed, which a loaded machine may not guarantee.
+    if Instant::now() < deadline {
+        assert_eq!(
+            early,
+            Err(timer.failure(Fault::Insufficiency(EmptyStock::default())))
+        );
+    }
+
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(early.or_else(|_| timer.consume()), Ok(deadline));
+    assert_eq!(timer.deadline(), None);
+    assert_eq!(
+        timer.consume(),
+        Err(timer.failure(Fault::Insufficiency(EmptyStock::default())))
+    );
+}
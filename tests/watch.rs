@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+use {
+    market::{Consumer, Producer},
+    market_types::watch::WatchChannel,
+};
+
+#[test]
+fn consumes_only_changes() {
+    let (producer, consumer) = WatchChannel::establish("watch");
+
+    assert!(!consumer.consume().unwrap_err().is_defect());
+    assert!(producer.produce(1_u8).is_ok());
+    assert!(producer.produce(2).is_ok());
+    let clone = consumer.clone();
+
+    assert_eq!(consumer.consume().ok(), Some(2));
+    assert!(!consumer.consume().unwrap_err().is_defect());
+    assert_eq!(consumer.latest(), Some(2));
+    assert_eq!(clone.consume().ok(), Some(2));
+
+    assert!(producer.produce(3).is_ok());
+    drop(producer);
+    assert_eq!(consumer.consume().ok(), Some(3));
+    assert!(consumer.consume().unwrap_err().is_defect());
+}
+
+#[test]
+fn withdrawn_demand() {
+    let (producer, consumer) = WatchChannel::establish("watch");
+
+    drop(consumer);
+    assert!(producer.produce(1_u8).is_err());
+}